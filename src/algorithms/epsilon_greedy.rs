@@ -1,32 +1,84 @@
+use rand::distr::Distribution;
+use rand::distr::weighted::WeightedIndex;
 use rand::prelude::IndexedRandom;
 use rand::rngs::StdRng;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
 
-use crate::traits::entities::{Action, ActionStorage, Context, Reward};
-use crate::traits::policy::BanditPolicy;
+use crate::traits::entities::{Action, ActionStorage, Context, DummyContext, Reward};
+use crate::traits::policy::{BanditPolicy, Direction, PolicyCheckpoint};
 use crate::utils::error::OctopusError;
 use rand::{Rng, SeedableRng};
 
+/// Strategy for resolving ties when several actions share the best average reward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreak {
+    /// Pick the tied action with the smallest ID.
+    #[default]
+    FirstById,
+    /// Pick the tied action with the fewest pulls, falling back to the smallest ID.
+    LeastPulled,
+    /// Pick uniformly at random among the tied actions, using the policy's internal RNG.
+    Random,
+}
+
+/// Strategy for picking an action in the exploration branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExplorationStrategy {
+    /// Pick uniformly at random among all actions.
+    #[default]
+    Uniform,
+    /// Pick with probability proportional to `1 / (pulls + 1)`, so rarely-pulled actions are explored more.
+    InverseCount,
+}
+
+/// Branch of the epsilon-greedy strategy that produced a selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionReason {
+    /// The action was picked at random (probability `epsilon`).
+    Explore,
+    /// The action was picked for having the best average reward.
+    Exploit,
+}
+
+/// Exponential decay of epsilon with the number of pulls, as configured by `new_decaying`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct EpsilonDecay {
+    min_epsilon: f64,
+    decay: f64,
+}
+
 /// Epsilon-Greedy policy for Multi-Armed Bandit problems.
 ///
-/// With probability `epsilon`, selects a random action (exploration).
-/// With probability `1 - epsilon`, selects the action with the highest average reward (exploitation).
+/// With probability `epsilon`, selects a random action (exploration), as configured by `ExplorationStrategy`.
+/// With probability `1 - epsilon`, selects the action with the best average reward (exploitation),
+/// i.e. the highest under `Direction::Maximize` and the lowest under `Direction::Minimize`.
+/// Ties during exploitation are resolved by the configured `TieBreak` strategy.
 ///
 /// Generic over action, reward, and context types. Context is ignored (non-contextual), but required for trait bounds.
 #[derive(Debug)]
-pub struct EpsilonGreedyPolicy<A, R, C>
+pub struct EpsilonGreedyPolicy<A, R, C = DummyContext>
 where
     C: Context,
     A: Action,
     R: Reward,
 {
     epsilon: f64,
+    epsilon_decay: Option<EpsilonDecay>,
     counts: HashMap<u32, u64>,
     sum_rewards: HashMap<u32, f64>,
     action_map: ActionStorage<A>,
     total_pulls: u64,
+    tie_break: TieBreak,
+    direction: Direction,
+    exploration: ExplorationStrategy,
+    exploration_budget: Option<f64>,
+    explore_count: AtomicU64,
+    exploit_count: AtomicU64,
+    frozen: bool,
+    seed: u64,
     rng: Mutex<StdRng>,
     _phantom: PhantomData<(R, C)>,
 }
@@ -44,6 +96,20 @@ where
     ///
     /// Returns an error if `epsilon` is out of bounds or if actions are empty.
     pub fn new(epsilon: f64, initial_actions: &[A]) -> Result<Self, OctopusError> {
+        Self::new_seeded(epsilon, initial_actions, (epsilon * 10.0) as u64)
+    }
+
+    /// Creates a new EpsilonGreedyPolicy whose RNG is seeded with `seed`.
+    ///
+    /// `new` derives its seed from `epsilon`; use this constructor to control the exploration stream,
+    /// e.g. to run independent replicas with the same epsilon.
+    ///
+    /// Returns an error if `epsilon` is out of bounds or if actions are empty.
+    pub fn new_seeded(
+        epsilon: f64,
+        initial_actions: &[A],
+        seed: u64,
+    ) -> Result<Self, OctopusError> {
         if !(0.0..=1.0).contains(&epsilon) {
             return Err(OctopusError::InvalidParameter {
                 parameter_name: "epsilon".to_string(),
@@ -57,15 +123,373 @@ where
             initial_actions.iter().map(|action| (action.id(), 0.0)).collect();
         Ok(EpsilonGreedyPolicy {
             epsilon,
+            epsilon_decay: None,
             counts,
             sum_rewards,
             action_map: ActionStorage::new(initial_actions)?,
             total_pulls: 0,
-            rng: Mutex::new(StdRng::seed_from_u64((epsilon * 10.0) as u64)),
+            tie_break: TieBreak::default(),
+            direction: Direction::default(),
+            exploration: ExplorationStrategy::default(),
+            exploration_budget: None,
+            explore_count: AtomicU64::new(0),
+            exploit_count: AtomicU64::new(0),
+            frozen: false,
+            seed,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
             _phantom: PhantomData,
         })
     }
 
+    /// Creates a new EpsilonGreedyPolicy whose arm statistics are seeded from historical data.
+    ///
+    /// * `history` - `(pulls, total_reward)` already observed for each action ID; unspecified
+    ///   actions start without pulls.
+    ///
+    /// Returns an error if `epsilon` is out of bounds, if actions are empty, if a total reward is
+    /// not finite, or if the history refers to an unknown action ID. `reset` discards the history.
+    pub fn new_with_history(
+        epsilon: f64,
+        initial_actions: &[A],
+        history: HashMap<u32, (u64, f64)>,
+    ) -> Result<Self, OctopusError> {
+        let mut policy = Self::new(epsilon, initial_actions)?;
+        for (&action_id, &(pulls, total_reward)) in &history {
+            if !policy.action_map.contains_key(&action_id) {
+                return Err(OctopusError::InvalidParameter {
+                    parameter_name: format!("history[{}]", action_id),
+                    value: "unknown action id".to_string(),
+                    expected_range: "id of one of the initial actions".to_string(),
+                });
+            }
+            if !total_reward.is_finite() {
+                return Err(OctopusError::InvalidParameter {
+                    parameter_name: format!("history[{}].total_reward", action_id),
+                    value: total_reward.to_string(),
+                    expected_range: "finite value".to_string(),
+                });
+            }
+            policy.counts.insert(action_id, pulls);
+            policy.sum_rewards.insert(action_id, total_reward);
+            policy.total_pulls += pulls;
+        }
+        Ok(policy)
+    }
+
+    /// Creates a new EpsilonGreedyPolicy whose epsilon decays as pulls accumulate.
+    ///
+    /// The effective epsilon at selection time is `max(min_epsilon, initial_epsilon * decay^total_pulls)`,
+    /// so exploration fades as the policy learns but never drops below `min_epsilon`.
+    ///
+    /// * `initial_epsilon` - Probability of exploration before any pull (0.0 to 1.0).
+    /// * `min_epsilon` - Floor of the effective epsilon (0.0 to `initial_epsilon`).
+    /// * `decay` - Per-pull decay factor (greater than 0.0, up to 1.0).
+    /// * `initial_actions` - Slice of all possible actions.
+    ///
+    /// Returns an error if a parameter is out of bounds.
+    pub fn new_decaying(
+        initial_epsilon: f64,
+        min_epsilon: f64,
+        decay: f64,
+        initial_actions: &[A],
+    ) -> Result<Self, OctopusError> {
+        let mut policy = Self::new(initial_epsilon, initial_actions)?;
+        if !(0.0..=initial_epsilon).contains(&min_epsilon) {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "min_epsilon".to_string(),
+                value: min_epsilon.to_string(),
+                expected_range: format!("0.0 to {} inclusive", initial_epsilon),
+            });
+        }
+        if !(decay > 0.0 && decay <= 1.0) {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "decay".to_string(),
+                value: decay.to_string(),
+                expected_range: "greater than 0.0, up to 1.0 inclusive".to_string(),
+            });
+        }
+        policy.epsilon_decay = Some(EpsilonDecay { min_epsilon, decay });
+        Ok(policy)
+    }
+
+    /// Returns the exploration probability used for the next selection.
+    ///
+    /// This is the configured epsilon, or its decayed value for policies built with `new_decaying`.
+    pub fn effective_epsilon(&self) -> f64 {
+        match self.epsilon_decay {
+            Some(EpsilonDecay { min_epsilon, decay }) => {
+                let exponent = self.total_pulls.min(i32::MAX as u64) as i32;
+                (self.epsilon * decay.powi(exponent)).max(min_epsilon)
+            }
+            None => self.epsilon,
+        }
+    }
+
+    /// Sets the strategy used to break ties between equally good actions during exploitation.
+    ///
+    /// Defaults to `TieBreak::FirstById`.
+    pub fn with_tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
+
+    /// Sets whether rewards are maximized or minimized (e.g. costs) during exploitation.
+    ///
+    /// Defaults to `Direction::Maximize`.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets how the exploration branch picks a random action.
+    ///
+    /// Defaults to `ExplorationStrategy::Uniform`.
+    pub fn with_exploration(mut self, exploration: ExplorationStrategy) -> Self {
+        self.exploration = exploration;
+        self
+    }
+
+    /// Caps the fraction of decisions that may explore.
+    ///
+    /// A decision may only explore if the cumulative share of exploratory decisions, counting this
+    /// one, stays within `budget`; otherwise the policy exploits regardless of the epsilon roll.
+    /// The share therefore never exceeds the cap, and a budget of 0.0 disables exploration.
+    /// Returns an error if `budget` is outside 0.0 to 1.0.
+    pub fn with_exploration_budget(mut self, budget: f64) -> Result<Self, OctopusError> {
+        if !(0.0..=1.0).contains(&budget) {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "exploration_budget".to_string(),
+                value: budget.to_string(),
+                expected_range: "0.0 to 1.0 inclusive".to_string(),
+            });
+        }
+        self.exploration_budget = Some(budget);
+        Ok(self)
+    }
+
+    /// Returns the number of `(explore, exploit)` decisions made since creation or the last reset.
+    pub fn exploration_counts(&self) -> (u64, u64) {
+        (
+            self.explore_count.load(Ordering::Relaxed),
+            self.exploit_count.load(Ordering::Relaxed),
+        )
+    }
+
+    /// Removes dominated arms from the policy.
+    ///
+    /// An arm is pruned if it has been pulled at least `min_pulls` times and its average reward
+    /// trails the best average (in the configured `Direction`) by more than `max_avg_gap`.
+    /// The worst arms are pruned first, and at least two arms are always kept.
+    pub fn prune_arms(&mut self, min_pulls: u64, max_avg_gap: f64) {
+        let best_avg_reward = self
+            .action_map
+            .keys()
+            .map(|&action_id| self.get_average_reward(action_id))
+            .fold(self.direction.worst(), |best, avg| {
+                if self.direction.prefers(avg, best) {
+                    avg
+                } else {
+                    best
+                }
+            });
+
+        let mut candidates: Vec<(u32, f64)> = self
+            .action_map
+            .sorted_ids()
+            .into_iter()
+            .filter(|action_id| self.counts.get(action_id).copied().unwrap_or(0) >= min_pulls)
+            .map(|action_id| {
+                let gap = (best_avg_reward - self.get_average_reward(action_id)).abs();
+                (action_id, gap)
+            })
+            .filter(|&(_, gap)| gap > max_avg_gap)
+            .collect();
+        // Largest gap first, so the worst arms go if the two-arm floor is reached.
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        for (action_id, _) in candidates {
+            if self.action_map.len() <= 2 {
+                break;
+            }
+            self.action_map.remove(action_id);
+            self.counts.remove(&action_id);
+            self.sum_rewards.remove(&action_id);
+        }
+    }
+
+    /// Returns true if the exploration budget is set and exploring on the next decision would push
+    /// the share of exploratory decisions above it.
+    fn budget_exhausted(&self) -> bool {
+        let (explored, exploited) = self.exploration_counts();
+        self.exploration_budget.is_some_and(|budget| {
+            let decisions = explored + exploited;
+            (explored + 1) as f64 / (decisions + 1) as f64 > budget
+        })
+    }
+
+    /// Rolls the epsilon coin, honouring the exploration budget, and records the decision.
+    ///
+    /// With a single candidate there is nothing to explore: the decision is recorded as an exploit
+    /// without drawing from the RNG.
+    fn roll_explore(&self, rng: &mut StdRng, num_candidates: usize) -> bool {
+        let explore = num_candidates > 1
+            && rng.random_range(0.0..1.0) < self.effective_epsilon()
+            && !self.budget_exhausted();
+        if explore {
+            self.explore_count.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.exploit_count.fetch_add(1, Ordering::Relaxed);
+        }
+        explore
+    }
+
+    /// Returns the ID of a random action among `candidate_ids` according to the configured `ExplorationStrategy`.
+    ///
+    /// `candidate_ids` must be non-empty and sorted, so that equal seeds explore identical sequences.
+    fn explore_action_id(&self, rng: &mut StdRng, candidate_ids: &[u32]) -> u32 {
+        match self.exploration {
+            ExplorationStrategy::Uniform => *candidate_ids.choose(rng).unwrap(),
+            ExplorationStrategy::InverseCount => {
+                let weights = candidate_ids
+                    .iter()
+                    .map(|action_id| 1.0 / (*self.counts.get(action_id).unwrap_or(&0) + 1) as f64);
+                let weighted = WeightedIndex::new(weights)
+                    .expect("Inverse-count weights must be positive and finite.");
+                candidate_ids[weighted.sample(rng)]
+            }
+        }
+    }
+
+    /// Returns the IDs among `candidate_ids` sharing the best average reward, sorted ascending.
+    ///
+    /// If no average compares as best (every one is NaN), all candidates count as tied.
+    fn tied_best_ids(&self, candidate_ids: &[u32]) -> Vec<u32> {
+        let best_avg_reward = candidate_ids
+            .iter()
+            .map(|&action_id| self.get_average_reward(action_id))
+            .fold(self.direction.worst(), |best, avg| {
+                if self.direction.prefers(avg, best) {
+                    avg
+                } else {
+                    best
+                }
+            });
+        let mut tied_ids: Vec<u32> = candidate_ids
+            .iter()
+            .copied()
+            .filter(|&action_id| self.get_average_reward(action_id) == best_avg_reward)
+            .collect();
+        if tied_ids.is_empty() {
+            tied_ids = candidate_ids.to_vec();
+        }
+        tied_ids.sort_unstable();
+        tied_ids
+    }
+
+    /// Returns the ID of the least-pulled action among `tied_ids`, falling back to the smallest ID.
+    fn least_pulled_id(&self, tied_ids: &[u32]) -> u32 {
+        *tied_ids
+            .iter()
+            .min_by_key(|&action_id| (self.counts.get(action_id).unwrap_or(&0), *action_id))
+            .unwrap()
+    }
+
+    /// Returns the ID among `candidate_ids` with the best average reward, resolving ties with `tie_break`.
+    fn best_action_id(&self, rng: &mut StdRng, candidate_ids: &[u32]) -> u32 {
+        let tied_ids = self.tied_best_ids(candidate_ids);
+        match self.tie_break {
+            TieBreak::FirstById => tied_ids[0],
+            TieBreak::LeastPulled => self.least_pulled_id(&tied_ids),
+            TieBreak::Random => *tied_ids.choose(rng).unwrap(),
+        }
+    }
+
+    /// Returns the probability that the exploration branch picks `action_id`.
+    fn explore_probability(&self, action_id: u32) -> f64 {
+        match self.exploration {
+            ExplorationStrategy::Uniform => 1.0 / self.action_map.len() as f64,
+            ExplorationStrategy::InverseCount => {
+                let weight = |id: &u32| 1.0 / (*self.counts.get(id).unwrap_or(&0) + 1) as f64;
+                weight(&action_id) / self.action_map.keys().map(weight).sum::<f64>()
+            }
+        }
+    }
+
+    /// Returns the probability that the exploitation branch picks `action_id`.
+    fn exploit_probability(&self, action_id: u32) -> f64 {
+        let tied_ids = self.tied_best_ids(&self.action_map.sorted_ids());
+        let chosen = match self.tie_break {
+            TieBreak::FirstById => tied_ids[0] == action_id,
+            TieBreak::LeastPulled => self.least_pulled_id(&tied_ids) == action_id,
+            TieBreak::Random => {
+                return if tied_ids.contains(&action_id) {
+                    1.0 / tied_ids.len() as f64
+                } else {
+                    0.0
+                };
+            }
+        };
+        if chosen { 1.0 } else { 0.0 }
+    }
+
+    /// Selects an action like `choose_action`, also reporting whether it was explored or exploited.
+    /// Ignores context (non-contextual).
+    pub fn choose_action_with_reason(&self, _context: &C) -> (A, SelectionReason) {
+        let (chosen_id, reason) = self.select_action_id(&self.action_map.sorted_ids());
+        (self.action_map.get(&chosen_id).unwrap().clone(), reason)
+    }
+
+    /// Selects a slate of distinct actions using the epsilon-greedy strategy.
+    ///
+    /// With probability `epsilon`, returns a random subset of `slate_size` distinct actions;
+    /// otherwise returns the top `slate_size` actions by average reward (respecting `Direction`,
+    /// ties broken by smallest ID; NaN averages sort as if above every other value). Slates larger than the action set are truncated to all actions.
+    /// Ignores context (non-contextual).
+    pub fn choose_slate(&self, _context: &C, slate_size: usize) -> Vec<A> {
+        let mut action_ids = self.action_map.sorted_ids();
+        let slate_size = slate_size.min(action_ids.len());
+        let mut rng = self.rng.lock().unwrap();
+        let slate_ids: Vec<u32> = if self.roll_explore(&mut rng, action_ids.len()) {
+            // Explore: random distinct subset
+            action_ids.choose_multiple(&mut *rng, slate_size).copied().collect()
+        } else {
+            // Exploit: best averages first
+            action_ids.sort_by(|a, b| {
+                let (avg_a, avg_b) = (self.get_average_reward(*a), self.get_average_reward(*b));
+                match self.direction {
+                    Direction::Maximize => avg_b.total_cmp(&avg_a),
+                    Direction::Minimize => avg_a.total_cmp(&avg_b),
+                }
+                .then(a.cmp(b))
+            });
+            action_ids.truncate(slate_size);
+            action_ids
+        };
+
+        slate_ids.iter().map(|id| self.action_map.get(id).unwrap().clone()).collect()
+    }
+
+    /// Runs one epsilon-greedy draw over `candidate_ids` (non-empty, sorted) and returns the chosen
+    /// action ID with the branch taken. A lone candidate is exploited without any RNG draw.
+    fn select_action_id(&self, candidate_ids: &[u32]) -> (u32, SelectionReason) {
+        let mut rng = self.rng.lock().unwrap();
+        if self.roll_explore(&mut rng, candidate_ids.len()) {
+            // Explore: random action
+            (
+                self.explore_action_id(&mut rng, candidate_ids),
+                SelectionReason::Explore,
+            )
+        } else if let [only_id] = candidate_ids {
+            (*only_id, SelectionReason::Exploit)
+        } else {
+            // Exploit: action with best average reward
+            (
+                self.best_action_id(&mut rng, candidate_ids),
+                SelectionReason::Exploit,
+            )
+        }
+    }
+
     /// Returns the average reward for the given action ID.
     /// Returns 0.0 if the action has not been selected yet.
     fn get_average_reward(&self, action_id: u32) -> f64 {
@@ -89,11 +513,20 @@ where
     fn clone(&self) -> Self {
         EpsilonGreedyPolicy {
             epsilon: self.epsilon,
+            epsilon_decay: self.epsilon_decay,
             counts: self.counts.clone(),
             sum_rewards: self.sum_rewards.clone(),
             action_map: self.action_map.clone(),
             total_pulls: self.total_pulls,
-            rng: Mutex::new(StdRng::seed_from_u64((self.epsilon * 10.0) as u64)),
+            tie_break: self.tie_break,
+            direction: self.direction,
+            exploration: self.exploration,
+            exploration_budget: self.exploration_budget,
+            explore_count: AtomicU64::new(self.explore_count.load(Ordering::Relaxed)),
+            exploit_count: AtomicU64::new(self.exploit_count.load(Ordering::Relaxed)),
+            frozen: self.frozen,
+            seed: self.seed,
+            rng: Mutex::new(StdRng::seed_from_u64(self.seed)),
             _phantom: PhantomData,
         }
     }
@@ -108,52 +541,187 @@ where
 {
     /// Selects an action using the epsilon-greedy strategy.
     /// Ignores context (non-contextual).
-    fn choose_action(&self, _context: &C) -> A {
-        let mut rng = self.rng.lock().unwrap();
-        let random_float: f64 = rng.random_range(0.0..1.0);
-        if random_float < self.epsilon {
-            // Explore: random action
-            let action_ids: Vec<&u32> = self.action_map.keys().collect();
-            let rand_id = action_ids.choose(&mut rng).unwrap();
-            self.action_map.get(rand_id).unwrap().clone()
-        } else {
-            // Exploit: action with highest average reward
-            let mut best_action_id: u32 = *self.action_map.keys().next().unwrap();
-            let mut max_avg_reward: f64 = self.get_average_reward(best_action_id);
-            for &action_id in self.action_map.keys() {
-                let current_avg = self.get_average_reward(action_id);
-                if current_avg > max_avg_reward {
-                    max_avg_reward = current_avg;
-                    best_action_id = action_id;
-                }
-            }
-            self.action_map.get(&best_action_id).unwrap().clone()
+    fn choose_action(&self, context: &C) -> A {
+        self.choose_action_scored(context).0
+    }
+
+    /// Selects an action using the epsilon-greedy strategy and returns its average reward as the score.
+    /// This holds for both branches: the best average when exploiting, the random arm's average when exploring.
+    fn choose_action_scored(&self, _context: &C) -> (A, f64) {
+        let (chosen_id, _) = self.select_action_id(&self.action_map.sorted_ids());
+        (
+            self.action_map.get(&chosen_id).unwrap().clone(),
+            self.get_average_reward(chosen_id),
+        )
+    }
+
+    /// Runs the epsilon-greedy draw over the actions satisfying `predicate` only: exploration
+    /// samples among them and exploitation takes the best of them.
+    fn choose_action_where(&self, _context: &C, predicate: impl Fn(&A) -> bool) -> Option<A> {
+        let candidate_ids: Vec<u32> = self
+            .action_map
+            .sorted_ids()
+            .into_iter()
+            .filter(|action_id| predicate(&self.action_map[action_id]))
+            .collect();
+        if candidate_ids.is_empty() {
+            return None;
         }
+        let (chosen_id, _) = self.select_action_id(&candidate_ids);
+        Some(self.action_map[&chosen_id].clone())
     }
 
-    /// Updates the statistics for the selected action and received reward.
+    /// Updates the statistics for the selected action and received reward, unless learning is frozen.
     /// Ignores context (non-contextual).
     fn update(&mut self, _context: &C, action: &A, reward: &R) {
+        if self.frozen {
+            return;
+        }
         let action_id = action.id();
         *self.counts.entry(action_id).or_insert(0) += 1;
         *self.sum_rewards.entry(action_id).or_insert(0.0) += reward.value();
         self.total_pulls += 1;
     }
 
-    /// Resets all statistics to their initial state.
+    fn set_frozen(&mut self, frozen: bool) {
+        self.frozen = frozen;
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Adds the pull counts and reward sums of `other` to this policy's statistics.
+    /// Explore/exploit counts are left untouched, as they describe this policy's own decisions.
+    /// Does nothing while learning is frozen.
+    fn merge(&mut self, other: &Self) -> Result<(), OctopusError> {
+        if self.action_map.sorted_ids() != other.action_map.sorted_ids() {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "other".to_string(),
+                value: format!("{:?}", other.action_map.sorted_ids()),
+                expected_range: format!(
+                    "policy with action ids {:?}",
+                    self.action_map.sorted_ids()
+                ),
+            });
+        }
+        if self.frozen {
+            return Ok(());
+        }
+        for &action_id in other.action_map.keys() {
+            *self.counts.get_mut(&action_id).unwrap() += other.counts[&action_id];
+            *self.sum_rewards.get_mut(&action_id).unwrap() += other.sum_rewards[&action_id];
+        }
+        self.total_pulls += other.total_pulls;
+        Ok(())
+    }
+
+    /// Resets all statistics, including explore/exploit counts, to their initial state.
+    /// The RNG continues its stream; see `reset_rng`.
     fn reset(&mut self) {
         self.total_pulls = 0;
+        self.explore_count.store(0, Ordering::Relaxed);
+        self.exploit_count.store(0, Ordering::Relaxed);
         for &action_id in self.action_map.keys() {
             *self.counts.get_mut(&action_id).unwrap() = 0;
             *self.sum_rewards.get_mut(&action_id).unwrap() = 0.0;
         }
     }
+
+    fn reset_rng(&mut self) {
+        self.rng = Mutex::new(StdRng::seed_from_u64(self.seed));
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.reset_rng();
+    }
+
+    /// Returns the average reward of the action (0.0 if never pulled).
+    fn expected_reward(&self, action: &A) -> Option<f64> {
+        self.action_map
+            .contains_key(&action.id())
+            .then(|| self.get_average_reward(action.id()))
+    }
+
+    /// Returns `epsilon * p_explore + (1 - epsilon) * p_exploit` for the action, using the
+    /// effective epsilon (0 while exploring would exceed the exploration budget) and the configured tie-break.
+    fn selection_probability(&self, _context: &C, action: &A) -> Option<f64> {
+        let action_id = action.id();
+        if !self.action_map.contains_key(&action_id) {
+            return None;
+        }
+        let epsilon = if self.budget_exhausted() {
+            0.0
+        } else {
+            self.effective_epsilon()
+        };
+        Some(
+            epsilon * self.explore_probability(action_id)
+                + (1.0 - epsilon) * self.exploit_probability(action_id),
+        )
+    }
+
+    fn selection_entropy(&self, context: &C) -> f64 {
+        self.action_map
+            .values()
+            .filter_map(|action| self.selection_probability(context, action))
+            .filter(|&p| p > 0.0)
+            .map(|p| -p * p.ln())
+            .sum()
+    }
+
+    fn action_ids(&self) -> Vec<u32> {
+        self.action_map.sorted_ids()
+    }
+
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        Ok(PolicyCheckpoint::EpsilonGreedy {
+            counts: self.action_map.known_entries(&self.counts),
+            sum_rewards: self.action_map.known_entries(&self.sum_rewards),
+            total_pulls: self.total_pulls,
+            explore_count: self.explore_count.load(Ordering::Relaxed),
+            exploit_count: self.exploit_count.load(Ordering::Relaxed),
+            seed: self.seed,
+        })
+    }
+
+    fn restore(&mut self, checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        let PolicyCheckpoint::EpsilonGreedy {
+            counts,
+            sum_rewards,
+            total_pulls,
+            explore_count,
+            exploit_count,
+            seed,
+        } = checkpoint
+        else {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "expected an EpsilonGreedy checkpoint".to_string(),
+            });
+        };
+        if !self.action_map.has_same_ids(counts) || !self.action_map.has_same_ids(sum_rewards) {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "checkpoint action ids do not match the policy's actions".to_string(),
+            });
+        }
+
+        self.counts = counts.clone();
+        self.sum_rewards = sum_rewards.clone();
+        self.total_pulls = *total_pulls;
+        self.explore_count.store(*explore_count, Ordering::Relaxed);
+        self.exploit_count.store(*exploit_count, Ordering::Relaxed);
+        self.seed = *seed;
+        self.rng = Mutex::new(StdRng::seed_from_u64(*seed));
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::traits::entities::{Action, DummyContext, NumericAction};
+    use std::collections::HashSet;
 
     #[derive(Debug, Clone, PartialEq)]
     struct DummyReward(f64);
@@ -267,12 +835,12 @@ mod tests {
         let dummy_context = DummyContext;
 
         // Simulate some pulls to establish average rewards
-        policy.update(&dummy_context, actions.get(0).unwrap(), &DummyReward(1.0)); // Avg: 1.0
+        policy.update(&dummy_context, actions.first().unwrap(), &DummyReward(1.0)); // Avg: 1.0
         policy.update(&dummy_context, actions.get(1).unwrap(), &DummyReward(10.0)); // Avg: 10.0
         policy.update(&dummy_context, actions.get(2).unwrap(), &DummyReward(12.0)); // Avg: 12.0
-        policy.update(&dummy_context, actions.get(0).unwrap(), &DummyReward(5.0)); // Avg: 3.0
+        policy.update(&dummy_context, actions.first().unwrap(), &DummyReward(5.0)); // Avg: 3.0
 
-        let reward0 = policy.get_average_reward(actions.get(0).unwrap().id());
+        let reward0 = policy.get_average_reward(actions.first().unwrap().id());
         let reward1 = policy.get_average_reward(actions.get(1).unwrap().id());
         let reward2 = policy.get_average_reward(actions.get(2).unwrap().id());
         // The "Good Action" should have the highest average reward
@@ -293,7 +861,7 @@ mod tests {
             NumericAction::new(10i32, "Action A"),
             NumericAction::new(10i32, "Action B"),
         ];
-        let id0 = actions.get(0).unwrap().id();
+        let id0 = actions.first().unwrap().id();
         let id1 = actions.get(1).unwrap().id();
         // Epsilon = 1.0 means always explore (random choice)
         let policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
@@ -340,7 +908,7 @@ mod tests {
             NumericAction::new(10i32, "Action B"),
         ];
 
-        let id0 = actions.get(0).unwrap().id();
+        let id0 = actions.first().unwrap().id();
         let id1 = actions.get(1).unwrap().id();
 
         let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
@@ -350,8 +918,8 @@ mod tests {
 
         let dummy_context = DummyContext;
 
-        policy.update(&dummy_context, &actions.get(0).unwrap(), &DummyReward(10.0));
-        policy.update(&dummy_context, &actions.get(1).unwrap(), &DummyReward(20.0));
+        policy.update(&dummy_context, actions.first().unwrap(), &DummyReward(10.0));
+        policy.update(&dummy_context, actions.get(1).unwrap(), &DummyReward(20.0));
 
         assert_eq!(policy.total_pulls, 2);
         assert_eq!(*policy.counts.get(&id0).unwrap(), 1);
@@ -362,8 +930,870 @@ mod tests {
         policy.reset();
         assert_eq!(policy.total_pulls, 0);
         for action_id in policy.action_map.keys() {
-            assert_eq!(*policy.counts.get(&action_id).unwrap(), 0);
-            assert_eq!(*policy.sum_rewards.get(&action_id).unwrap(), 0.0);
+            assert_eq!(*policy.counts.get(action_id).unwrap(), 0);
+            assert_eq!(*policy.sum_rewards.get(action_id).unwrap(), 0.0);
+        }
+    }
+
+    fn build_tied_policy(
+        tie_break: TieBreak,
+    ) -> (
+        Vec<NumericAction<i32>>,
+        EpsilonGreedyPolicy<NumericAction<i32>, DummyReward, DummyContext>,
+    ) {
+        let actions = vec![
+            NumericAction::with_id(2, 0i32, "Action C"),
+            NumericAction::with_id(0, 0i32, "Action A"),
+            NumericAction::with_id(1, 0i32, "Action B"),
+        ];
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.0, &actions,
+        )
+        .unwrap()
+        .with_tie_break(tie_break);
+        let dummy_context = DummyContext;
+
+        // All actions average 1.0, with pull counts A: 3, B: 2, C: 1.
+        for (action, pulls) in actions.iter().zip([1, 3, 2]) {
+            for _ in 0..pulls {
+                policy.update(&dummy_context, action, &DummyReward(1.0));
+            }
+        }
+        (actions, policy)
+    }
+
+    #[test]
+    fn test_epsilon_greedy_tie_break_first_by_id() {
+        let (_, policy) = build_tied_policy(TieBreak::FirstById);
+        for _ in 0..50 {
+            assert_eq!(policy.choose_action(&DummyContext).id(), 0);
+        }
+    }
+
+    #[test]
+    fn test_epsilon_greedy_tie_break_least_pulled() {
+        let (_, policy) = build_tied_policy(TieBreak::LeastPulled);
+        for _ in 0..50 {
+            assert_eq!(policy.choose_action(&DummyContext).id(), 2);
+        }
+    }
+
+    #[test]
+    fn test_epsilon_greedy_tie_break_random() {
+        let (actions, policy) = build_tied_policy(TieBreak::Random);
+        let mut counts_chosen: HashMap<u32, u64> = HashMap::new();
+        for _ in 0..300 {
+            *counts_chosen.entry(policy.choose_action(&DummyContext).id()).or_insert(0) += 1;
+        }
+        // Every tied action should be picked at some point.
+        for action in &actions {
+            assert!(*counts_chosen.get(&action.id()).unwrap_or(&0) > 0);
+        }
+    }
+
+    #[test]
+    fn test_epsilon_greedy_scored_matches_average_when_exploiting() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "Action A"),
+            NumericAction::with_id(1, 20i32, "Action B"),
+        ];
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.0, &actions,
+        )
+        .unwrap();
+        let dummy_context = DummyContext;
+
+        policy.update(&dummy_context, &actions[0], &DummyReward(2.0));
+        policy.update(&dummy_context, &actions[1], &DummyReward(4.0));
+        policy.update(&dummy_context, &actions[1], &DummyReward(5.0));
+
+        let (chosen, score) = policy.choose_action_scored(&dummy_context);
+        assert_eq!(chosen.id(), 1);
+        assert_eq!(score, policy.get_average_reward(chosen.id()));
+        assert_eq!(score, 4.5);
+    }
+
+    #[test]
+    fn test_epsilon_greedy_minimize_picks_lowest_mean() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "Cheap"),
+            NumericAction::with_id(1, 20i32, "Moderate"),
+            NumericAction::with_id(2, 30i32, "Expensive"),
+        ];
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.0, &actions,
+        )
+        .unwrap()
+        .with_direction(Direction::Minimize);
+        let dummy_context = DummyContext;
+
+        policy.update(&dummy_context, &actions[0], &DummyReward(1.0));
+        policy.update(&dummy_context, &actions[1], &DummyReward(5.0));
+        policy.update(&dummy_context, &actions[2], &DummyReward(9.0));
+
+        for _ in 0..50 {
+            assert_eq!(policy.choose_action(&dummy_context).name(), "Cheap");
         }
     }
+
+    #[test]
+    fn test_epsilon_greedy_checkpoint_round_trip() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "Action A"),
+            NumericAction::with_id(1, 20i32, "Action B"),
+        ];
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.0, &actions,
+        )
+        .unwrap();
+        let dummy_context = DummyContext;
+        policy.update(&dummy_context, &actions[0], &DummyReward(1.0));
+        policy.update(&dummy_context, &actions[1], &DummyReward(3.0));
+        policy.update(&dummy_context, &actions[1], &DummyReward(4.0));
+        policy.choose_action(&dummy_context);
+        // The lenient update records an unknown ID; the checkpoint leaves it out.
+        let phantom = NumericAction::with_id(9, 90i32, "Phantom");
+        policy.update(&dummy_context, &phantom, &DummyReward(1.0));
+
+        let checkpoint = policy.checkpoint().unwrap();
+        let mut restored =
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                0.0, &actions,
+            )
+            .unwrap();
+        restored.restore(&checkpoint).unwrap();
+
+        assert_eq!(restored.checkpoint().unwrap(), checkpoint);
+        assert_eq!(restored.exploration_counts(), (0, 1));
+        assert_eq!(restored.total_pulls, 4);
+        assert_eq!(restored.get_average_reward(1), 3.5);
+        assert_eq!(restored.choose_action(&dummy_context).id(), 1);
+    }
+
+    #[test]
+    fn test_epsilon_greedy_restore_rejects_mismatched_checkpoint() {
+        let actions = vec![NumericAction::with_id(0, 10i32, "Action A")];
+        let other_actions = vec![NumericAction::with_id(7, 10i32, "Action Z")];
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.1, &actions,
+        )
+        .unwrap();
+        let other = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.1,
+            &other_actions,
+        )
+        .unwrap();
+
+        let err = policy.restore(&other.checkpoint().unwrap()).unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidCheckpoint {
+                reason: "checkpoint action ids do not match the policy's actions".to_string(),
+            }
+        );
+
+        let thompson_checkpoint = PolicyCheckpoint::ThompsonSampling {
+            alpha_params: HashMap::new(),
+            beta_params: HashMap::new(),
+            observed_range: None,
+            seed: 0,
+        };
+        assert!(policy.restore(&thompson_checkpoint).is_err());
+    }
+
+    #[test]
+    fn test_epsilon_greedy_inverse_count_exploration_balances_pulls() {
+        let actions: Vec<NumericAction<i32>> =
+            (0..5).map(|i| NumericAction::with_id(i, i as i32, "arm")).collect();
+        let dummy_context = DummyContext;
+
+        let pull_count_variance = |exploration: ExplorationStrategy| {
+            let mut policy =
+                EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                    1.0, &actions,
+                )
+                .unwrap()
+                .with_exploration(exploration);
+            for _ in 0..2000 {
+                let action = policy.choose_action(&dummy_context);
+                policy.update(&dummy_context, &action, &DummyReward(0.0));
+            }
+            let mean = policy.total_pulls as f64 / actions.len() as f64;
+            policy.counts.values().map(|&c| (c as f64 - mean).powi(2)).sum::<f64>()
+                / actions.len() as f64
+        };
+
+        let uniform_variance = pull_count_variance(ExplorationStrategy::Uniform);
+        let weighted_variance = pull_count_variance(ExplorationStrategy::InverseCount);
+        assert!(
+            weighted_variance < uniform_variance,
+            "weighted: {}, uniform: {}",
+            weighted_variance,
+            uniform_variance
+        );
+    }
+
+    #[test]
+    fn test_epsilon_greedy_action_ids_and_count() {
+        let actions = vec![
+            NumericAction::with_id(5, 10i32, "Action A"),
+            NumericAction::with_id(2, 20i32, "Action B"),
+            NumericAction::with_id(9, 30i32, "Action C"),
+        ];
+        let policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.1, &actions,
+        )
+        .unwrap();
+        assert_eq!(policy.n_actions(), 3);
+        assert_eq!(policy.action_ids(), vec![2, 5, 9]);
+
+        let smaller = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.1,
+            &actions[..1],
+        )
+        .unwrap();
+        assert_eq!(smaller.n_actions(), 1);
+        assert_eq!(smaller.action_ids(), vec![5]);
+    }
+
+    #[test]
+    fn test_epsilon_greedy_fresh_keeps_configuration() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "Action A"),
+            NumericAction::with_id(1, 20i32, "Action B"),
+        ];
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.3, &actions,
+        )
+        .unwrap()
+        .with_tie_break(TieBreak::LeastPulled);
+        let dummy_context = DummyContext;
+        policy.update(&dummy_context, &actions[0], &DummyReward(1.0));
+        policy.update(&dummy_context, &actions[1], &DummyReward(2.0));
+
+        let fresh = policy.fresh();
+        assert_eq!(fresh.total_pulls, 0);
+        assert!(fresh.counts.values().all(|&count| count == 0));
+        assert_eq!(fresh.epsilon, 0.3);
+        assert_eq!(fresh.tie_break, TieBreak::LeastPulled);
+        assert_eq!(fresh.action_ids(), policy.action_ids());
+        // The original policy keeps its learned state.
+        assert_eq!(policy.total_pulls, 2);
+    }
+
+    #[test]
+    fn test_epsilon_greedy_selection_reason() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "Action A"),
+            NumericAction::with_id(1, 20i32, "Action B"),
+        ];
+        let dummy_context = DummyContext;
+
+        let greedy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.0, &actions,
+        )
+        .unwrap();
+        let random = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            1.0, &actions,
+        )
+        .unwrap();
+
+        for _ in 0..100 {
+            let (_, reason) = greedy.choose_action_with_reason(&dummy_context);
+            assert_eq!(reason, SelectionReason::Exploit);
+            let (_, reason) = random.choose_action_with_reason(&dummy_context);
+            assert_eq!(reason, SelectionReason::Explore);
+        }
+    }
+
+    #[test]
+    fn test_epsilon_greedy_non_contextual_entrypoints() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "Action A"),
+            NumericAction::with_id(1, 20i32, "Action B"),
+        ];
+        let mut policy: EpsilonGreedyPolicy<NumericAction<i32>, DummyReward> =
+            EpsilonGreedyPolicy::new(0.0, &actions).unwrap();
+
+        policy.observe(&actions[0], &DummyReward(1.0));
+        policy.observe(&actions[1], &DummyReward(3.0));
+
+        assert_eq!(policy.total_pulls, 2);
+        assert_eq!(policy.choose().id(), 1);
+    }
+
+    #[test]
+    fn test_epsilon_greedy_choose_slate() {
+        let actions: Vec<NumericAction<i32>> =
+            (0..6).map(|i| NumericAction::with_id(i, i as i32, "arm")).collect();
+        let dummy_context = DummyContext;
+
+        let mut greedy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.0, &actions,
+        )
+        .unwrap();
+        for action in &actions {
+            greedy.update(&dummy_context, action, &DummyReward(action.id() as f64));
+        }
+        let slate: Vec<u32> =
+            greedy.choose_slate(&dummy_context, 3).iter().map(|a| a.id()).collect();
+        assert_eq!(slate, vec![5, 4, 3]);
+
+        let random = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            1.0, &actions,
+        )
+        .unwrap();
+        for _ in 0..100 {
+            let mut slate: Vec<u32> =
+                random.choose_slate(&dummy_context, 4).iter().map(|a| a.id()).collect();
+            assert_eq!(slate.len(), 4);
+            slate.sort_unstable();
+            slate.dedup();
+            assert_eq!(slate.len(), 4, "slate must not contain duplicates");
+        }
+
+        assert_eq!(random.choose_slate(&dummy_context, 10).len(), actions.len());
+    }
+
+    #[test]
+    fn test_epsilon_greedy_nan_average_does_not_panic() {
+        let actions: Vec<NumericAction<i32>> =
+            (0..4).map(|id| NumericAction::with_id(id, id as i32, "arm")).collect();
+        let dummy_context = DummyContext;
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.0, &actions,
+        )
+        .unwrap();
+        for (action, reward) in actions.iter().zip([f64::NAN, 0.9, 0.5, 0.1]) {
+            policy.update(&dummy_context, action, &DummyReward(reward));
+        }
+
+        let slate: Vec<u32> =
+            policy.choose_slate(&dummy_context, 4).iter().map(|a| a.id()).collect();
+        assert_eq!(slate, vec![0, 1, 2, 3]);
+
+        // The NaN arm has no measurable gap, so only the dominated finite arms go.
+        policy.prune_arms(1, 0.3);
+        assert_eq!(policy.action_ids(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_epsilon_greedy_all_nan_averages_do_not_panic() {
+        let actions: Vec<NumericAction<i32>> =
+            (0..3).map(|id| NumericAction::with_id(id, id as i32, "arm")).collect();
+        let dummy_context = DummyContext;
+
+        for tie_break in [TieBreak::FirstById, TieBreak::LeastPulled, TieBreak::Random] {
+            let mut policy =
+                EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                    0.0, &actions,
+                )
+                .unwrap()
+                .with_tie_break(tie_break);
+            for action in &actions {
+                policy.update(&dummy_context, action, &DummyReward(f64::NAN));
+            }
+
+            let chosen = policy.choose_action(&dummy_context);
+            assert!(policy.action_ids().contains(&chosen.id()));
+            if tie_break == TieBreak::FirstById {
+                assert_eq!(chosen.id(), 0);
+                assert_eq!(
+                    policy.selection_probability(&dummy_context, &actions[0]),
+                    Some(1.0)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_epsilon_greedy_exploration_budget_caps_exploration() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "Action A"),
+            NumericAction::with_id(1, 20i32, "Action B"),
+        ];
+        let dummy_context = DummyContext;
+
+        let err = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            1.0, &actions,
+        )
+        .unwrap()
+        .with_exploration_budget(1.5)
+        .unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "exploration_budget".to_string(),
+                value: "1.5".to_string(),
+                expected_range: "0.0 to 1.0 inclusive".to_string(),
+            }
+        );
+
+        // Epsilon 1.0 would always explore; the budget caps it at 5% of decisions.
+        let policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            1.0, &actions,
+        )
+        .unwrap()
+        .with_exploration_budget(0.05)
+        .unwrap();
+
+        let mut reasons = Vec::new();
+        for decisions in 1..=1000 {
+            reasons.push(policy.choose_action_with_reason(&dummy_context).1);
+            let (explored, _) = policy.exploration_counts();
+            assert!(
+                explored as f64 / decisions as f64 <= 0.05,
+                "explored {} of {} decisions",
+                explored,
+                decisions
+            );
+        }
+        let (explored, exploited) = policy.exploration_counts();
+        assert_eq!(explored + exploited, 1000);
+        assert_eq!(explored, 50);
+        // The first exploration fits the cap only at the 20th decision.
+        assert!(reasons[..19].iter().all(|&reason| reason == SelectionReason::Exploit));
+        assert_eq!(reasons[19], SelectionReason::Explore);
+
+        // A zero budget never explores.
+        let policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            1.0, &actions,
+        )
+        .unwrap()
+        .with_exploration_budget(0.0)
+        .unwrap();
+        for _ in 0..100 {
+            assert_eq!(
+                policy.choose_action_with_reason(&dummy_context).1,
+                SelectionReason::Exploit
+            );
+        }
+        assert_eq!(policy.exploration_counts(), (0, 100));
+    }
+
+    #[test]
+    fn test_epsilon_greedy_prune_arms() {
+        let actions: Vec<NumericAction<i32>> =
+            (0..4).map(|id| NumericAction::with_id(id, id as i32, "arm")).collect();
+        let dummy_context = DummyContext;
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.0, &actions,
+        )
+        .unwrap();
+
+        // Arms 0-2 are close contenders; arm 3 is clearly bad.
+        for (action, reward) in actions.iter().zip([1.0, 0.95, 0.9, 0.1]) {
+            for _ in 0..10 {
+                policy.update(&dummy_context, action, &DummyReward(reward));
+            }
+        }
+        policy.prune_arms(10, 0.2);
+        assert_eq!(policy.action_ids(), vec![0, 1, 2]);
+
+        // Arms below `min_pulls` are never pruned.
+        policy.prune_arms(11, 0.0);
+        assert_eq!(policy.action_ids(), vec![0, 1, 2]);
+
+        // Even with no tolerance, the two best arms are kept.
+        policy.prune_arms(10, 0.0);
+        assert_eq!(policy.action_ids(), vec![0, 1]);
+        assert_eq!(policy.choose_action(&dummy_context).id(), 0);
+    }
+
+    #[test]
+    fn test_epsilon_greedy_reset_keeps_stream_and_reset_rng_restarts_it() {
+        let actions: Vec<NumericAction<i32>> =
+            (0..5).map(|id| NumericAction::with_id(id, id as i32, "arm")).collect();
+        let dummy_context = DummyContext;
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            1.0, &actions,
+        )
+        .unwrap();
+        let reference = policy.fresh();
+        let draw = |policy: &EpsilonGreedyPolicy<NumericAction<i32>, DummyReward, DummyContext>| -> Vec<u32> {
+            (0..20).map(|_| policy.choose_action(&dummy_context).id()).collect()
+        };
+        let reference_stream: Vec<u32> =
+            draw(&reference).into_iter().chain(draw(&reference)).collect();
+
+        let first = draw(&policy);
+        policy.update(&dummy_context, &actions[0], &DummyReward(1.0));
+        policy.reset();
+        let after_reset = draw(&policy);
+        assert_eq!([first.clone(), after_reset].concat(), reference_stream);
+
+        policy.reset_rng();
+        assert_eq!(draw(&policy), first);
+    }
+
+    #[test]
+    fn test_epsilon_greedy_choose_and_update() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "Action A"),
+            NumericAction::with_id(1, 20i32, "Action B"),
+        ];
+        let dummy_context = DummyContext;
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.3, &actions,
+        )
+        .unwrap();
+
+        for expected_pulls in 1..=5u64 {
+            let chosen = policy
+                .choose_and_update(&dummy_context, |action| DummyReward(action.value() as f64));
+            assert!(actions.contains(&chosen));
+            match policy.checkpoint().unwrap() {
+                PolicyCheckpoint::EpsilonGreedy { total_pulls, .. } => {
+                    assert_eq!(total_pulls, expected_pulls)
+                }
+                other => panic!("unexpected checkpoint: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_epsilon_greedy_expected_reward() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "Action A"),
+            NumericAction::with_id(1, 20i32, "Action B"),
+        ];
+        let dummy_context = DummyContext;
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.1, &actions,
+        )
+        .unwrap();
+        for reward in [1.0, 4.0, 7.0] {
+            policy.update(&dummy_context, &actions[0], &DummyReward(reward));
+        }
+
+        assert_eq!(policy.expected_reward(&actions[0]), Some(4.0));
+        assert_eq!(policy.expected_reward(&actions[1]), Some(0.0));
+        assert_eq!(
+            policy.expected_reward(&NumericAction::with_id(9, 0i32, "Unknown")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_epsilon_greedy_decaying_exploration_drops() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "Action A"),
+            NumericAction::with_id(1, 20i32, "Action B"),
+        ];
+        let dummy_context = DummyContext;
+
+        let err =
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new_decaying(
+                0.5, 0.6, 0.99, &actions,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "min_epsilon".to_string(),
+                value: "0.6".to_string(),
+                expected_range: "0.0 to 0.5 inclusive".to_string(),
+            }
+        );
+
+        let mut policy =
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new_decaying(
+                1.0, 0.05, 0.99, &actions,
+            )
+            .unwrap();
+        let mut explore_shares = Vec::new();
+        for _block in 0..4 {
+            let (explored_before, _) = policy.exploration_counts();
+            for _ in 0..200 {
+                let action = policy.choose_action(&dummy_context);
+                policy.update(&dummy_context, &action, &DummyReward(1.0));
+            }
+            let (explored_after, _) = policy.exploration_counts();
+            explore_shares.push((explored_after - explored_before) as f64 / 200.0);
+        }
+
+        assert!(explore_shares[0] > 0.3, "shares: {:?}", explore_shares);
+        assert!(explore_shares[3] < 0.15, "shares: {:?}", explore_shares);
+        assert!((policy.effective_epsilon() - 0.05).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_epsilon_greedy_selection_probabilities_sum_to_one() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "Action A"),
+            NumericAction::with_id(1, 0i32, "Action B"),
+            NumericAction::with_id(2, 0i32, "Action C"),
+        ];
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.3, &actions,
+        )
+        .unwrap()
+        .with_exploration(ExplorationStrategy::InverseCount);
+        let dummy_context = DummyContext;
+        policy.update(&dummy_context, &actions[0], &DummyReward(1.0));
+        policy.update(&dummy_context, &actions[1], &DummyReward(5.0));
+        policy.update(&dummy_context, &actions[1], &DummyReward(5.0));
+
+        let probabilities: Vec<f64> = actions
+            .iter()
+            .map(|action| policy.selection_probability(&dummy_context, action).unwrap())
+            .collect();
+        assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        // Inverse-count weights are 1/2, 1/3 and 1 (total 11/6); B is the greedy action.
+        assert!((probabilities[1] - (0.7 + 0.3 * (1.0 / 3.0) / (11.0 / 6.0))).abs() < 1e-12);
+        assert!((probabilities[2] - 0.3 * 1.0 / (11.0 / 6.0)).abs() < 1e-12);
+
+        let unknown = NumericAction::with_id(9, 0i32, "Unknown");
+        assert_eq!(policy.selection_probability(&dummy_context, &unknown), None);
+    }
+
+    #[test]
+    fn test_epsilon_greedy_new_seeded_replays_exploration() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "Action A"),
+            NumericAction::with_id(1, 0i32, "Action B"),
+            NumericAction::with_id(2, 0i32, "Action C"),
+        ];
+        let dummy_context = DummyContext;
+        let run = |seed: u64| {
+            let policy =
+                EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new_seeded(
+                    0.5, &actions, seed,
+                )
+                .unwrap();
+            (0..100)
+                .map(|_| {
+                    let (action, reason) = policy.choose_action_with_reason(&dummy_context);
+                    (action.id(), reason)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(run(7), run(7));
+        assert_ne!(run(7), run(8));
+    }
+
+    #[test]
+    fn test_epsilon_greedy_try_update_rejects_unknown_action() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "Action A"),
+            NumericAction::with_id(1, 0i32, "Action B"),
+        ];
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.1, &actions,
+        )
+        .unwrap();
+        let dummy_context = DummyContext;
+        let typo = NumericAction::with_id(10, 0i32, "Action A");
+
+        assert_eq!(
+            policy.try_update(&dummy_context, &typo, &DummyReward(1.0)),
+            Err(OctopusError::UnknownAction { action_id: 10 })
+        );
+        assert!(!policy.counts.contains_key(&10));
+
+        assert_eq!(
+            policy.try_update(&dummy_context, &actions[1], &DummyReward(1.0)),
+            Ok(())
+        );
+        assert_eq!(policy.counts[&1], 1);
+    }
+
+    #[test]
+    fn test_epsilon_greedy_choose_action_where_excludes_expensive_arms() {
+        // The action value is its price.
+        let actions = vec![
+            NumericAction::with_id(0, 5i32, "cheap"),
+            NumericAction::with_id(1, 50i32, "expensive"),
+            NumericAction::with_id(2, 8i32, "affordable"),
+        ];
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.3, &actions,
+        )
+        .unwrap();
+        let dummy_context = DummyContext;
+        // The expensive arm is by far the best, so an unconstrained policy would exploit it.
+        for (action, reward) in actions.iter().zip([0.2, 0.9, 0.4]) {
+            policy.update(&dummy_context, action, &DummyReward(reward));
+        }
+
+        let in_budget = |action: &NumericAction<i32>| action.value() <= 10;
+        let mut chosen_ids = HashSet::new();
+        for _ in 0..200 {
+            let action = policy.choose_action_where(&dummy_context, in_budget).unwrap();
+            chosen_ids.insert(action.id());
+        }
+        assert_eq!(chosen_ids, HashSet::from([0, 2]));
+        assert_eq!(
+            policy.choose_action_where(&dummy_context, |action| action.value() > 100),
+            None
+        );
+    }
+
+    #[test]
+    fn test_epsilon_greedy_single_arm_skips_rng() {
+        let actions = vec![NumericAction::with_id(7, 1i32, "only")];
+        let policy =
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new_seeded(
+                1.0, &actions, 3,
+            )
+            .unwrap();
+        let dummy_context = DummyContext;
+
+        for _ in 0..10 {
+            assert_eq!(policy.choose_action(&dummy_context).id(), 7);
+        }
+        assert_eq!(policy.choose_action_scored(&dummy_context).0.id(), 7);
+        assert_eq!(
+            policy.choose_action_where(&dummy_context, |_| true).unwrap().id(),
+            7
+        );
+        assert_eq!(
+            policy.choose_action_with_reason(&dummy_context),
+            (actions[0].clone(), SelectionReason::Exploit)
+        );
+        assert_eq!(policy.choose_slate(&dummy_context, 3), actions);
+        // Every entry point records its decision as an exploit.
+        assert_eq!(policy.exploration_counts(), (0, 14));
+        let next_draw: u64 = policy.rng.lock().unwrap().random();
+        assert_eq!(next_draw, StdRng::seed_from_u64(3).random::<u64>());
+    }
+
+    #[test]
+    fn test_epsilon_greedy_new_with_history_favors_seeded_arm() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+            NumericAction::with_id(2, 2i32, "C"),
+        ];
+        let history = HashMap::from([(0, (100, 20.0)), (2, (500, 450.0))]);
+        let policy =
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new_with_history(
+                0.0, &actions, history,
+            )
+            .unwrap();
+        let dummy_context = DummyContext;
+
+        assert_eq!(policy.expected_reward(&actions[2]), Some(0.9));
+        assert_eq!(policy.expected_reward(&actions[1]), Some(0.0));
+        for _ in 0..10 {
+            assert_eq!(policy.choose_action(&dummy_context).id(), 2);
+        }
+
+        let err =
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new_with_history(
+                0.0,
+                &actions,
+                HashMap::from([(9, (1, 1.0))]),
+            )
+            .unwrap_err();
+        assert!(
+            matches!(err, OctopusError::InvalidParameter { ref parameter_name, .. } if parameter_name == "history[9]")
+        );
+    }
+
+    #[test]
+    fn test_epsilon_greedy_merge_matches_training_on_concatenated_data() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+        ];
+        let shard_a = [(0, 1.0), (1, 0.25), (0, 0.5)];
+        let shard_b = [(1, 0.75), (1, 1.0), (0, 0.0), (1, 0.5)];
+        let dummy_context = DummyContext;
+        let train = |data: &[(usize, f64)]| {
+            let mut policy =
+                EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                    0.1, &actions,
+                )
+                .unwrap();
+            for &(index, reward) in data {
+                policy.update(&dummy_context, &actions[index], &DummyReward(reward));
+            }
+            policy
+        };
+
+        let mut merged = train(&shard_a);
+        merged.merge(&train(&shard_b)).unwrap();
+        let combined = train(&[shard_a.as_slice(), shard_b.as_slice()].concat());
+        assert_eq!(merged.checkpoint().unwrap(), combined.checkpoint().unwrap());
+
+        let other_actions = vec![NumericAction::with_id(5, 5i32, "F")];
+        let other = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.1,
+            &other_actions,
+        )
+        .unwrap();
+        assert!(matches!(
+            merged.merge(&other),
+            Err(OctopusError::InvalidParameter { ref parameter_name, .. }) if parameter_name == "other"
+        ));
+    }
+
+    #[test]
+    fn test_epsilon_greedy_selection_entropy_drops_with_training() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+            NumericAction::with_id(2, 2i32, "C"),
+        ];
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.1, &actions,
+        )
+        .unwrap()
+        .with_tie_break(TieBreak::Random);
+        let dummy_context = DummyContext;
+        let max_entropy = (actions.len() as f64).ln();
+
+        // Untrained, every arm is tied, so the choice is uniform.
+        let fresh_entropy = policy.selection_entropy(&dummy_context);
+        assert!((fresh_entropy - max_entropy).abs() < 1e-12);
+
+        for _ in 0..50 {
+            for (action, reward) in actions.iter().zip([0.2, 0.9, 0.4]) {
+                policy.update(&dummy_context, action, &DummyReward(reward));
+            }
+        }
+        let trained_entropy = policy.selection_entropy(&dummy_context);
+        assert!(
+            trained_entropy < 0.5 * max_entropy,
+            "trained entropy {} vs max {}",
+            trained_entropy,
+            max_entropy
+        );
+    }
+
+    #[test]
+    fn test_epsilon_greedy_frozen_ignores_updates() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+        ];
+        let mut policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.1, &actions,
+        )
+        .unwrap();
+        let dummy_context = DummyContext;
+        policy.update(&dummy_context, &actions[0], &DummyReward(0.5));
+        let trained = policy.checkpoint().unwrap();
+
+        policy.set_frozen(true);
+        assert!(policy.is_frozen());
+        policy.update(&dummy_context, &actions[1], &DummyReward(1.0));
+        policy.update(&dummy_context, &actions[0], &DummyReward(0.0));
+        assert_eq!(policy.checkpoint().unwrap(), trained);
+
+        // Merging is learning too, so a frozen policy ignores it.
+        let mut replica = policy.fresh();
+        replica.set_frozen(false);
+        replica.update(&dummy_context, &actions[1], &DummyReward(1.0));
+        policy.merge(&replica).unwrap();
+        assert_eq!(policy.checkpoint().unwrap(), trained);
+
+        policy.set_frozen(false);
+        assert!(!policy.is_frozen());
+        policy.update(&dummy_context, &actions[1], &DummyReward(1.0));
+        assert_eq!(policy.counts[&1], 1);
+        assert_eq!(policy.expected_reward(&actions[1]), Some(1.0));
+    }
 }
@@ -0,0 +1,461 @@
+use ndarray::{Array1, Array2, Axis};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand_distr::{Distribution, StandardNormal};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::traits::entities::{Action, ActionStorage, Context, Reward};
+use crate::traits::policy::{BanditPolicy, PolicyCheckpoint};
+use crate::utils::error::OctopusError;
+
+/// Bayesian linear regression posterior `N(mean, covariance)` over one arm's weight vector.
+#[derive(Debug, Clone, PartialEq)]
+struct ArmPosterior {
+    mean: Array1<f64>,
+    covariance: Array2<f64>,
+}
+
+impl ArmPosterior {
+    /// Standard normal prior `N(0, I)`.
+    fn prior(dimension: usize) -> Self {
+        ArmPosterior {
+            mean: Array1::zeros(dimension),
+            covariance: Array2::eye(dimension),
+        }
+    }
+}
+
+/// Linear Thompson Sampling policy for contextual Multi-Armed Bandit problems.
+///
+/// Models the reward of each arm as `x · theta_arm` plus Gaussian noise of known variance, where `x`
+/// is the flattened `Context::to_ndarray` feature vector. Each arm keeps a Bayesian linear regression
+/// posterior over its weights; every step a weight vector is sampled from each posterior and the arm
+/// with the highest `x · theta_sampled` is chosen.
+///
+/// # Panics
+/// `choose_action` and `update` panic if a context's feature vector does not have `dimension` entries.
+#[derive(Debug)]
+pub struct LinearThompsonPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    dimension: usize,
+    noise_variance: f64,
+    posteriors: HashMap<u32, ArmPosterior>,
+    action_map: ActionStorage<A>,
+    seed: u64,
+    rng: Mutex<StdRng>,
+    frozen: bool,
+    _phantom: PhantomData<(R, C)>,
+}
+
+impl<A, R, C> LinearThompsonPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    /// Creates a new LinearThompsonPolicy with seeded RNG and a `N(0, I)` prior on every arm.
+    ///
+    /// * `dimension` - Number of context features.
+    /// * `noise_variance` - Variance of the reward noise around `x · theta`.
+    /// * `initial_actions` - Slice of all possible actions.
+    /// * `seed` - Seed for the weight sampling RNG.
+    ///
+    /// Returns an error if actions are empty, `dimension` is 0 or `noise_variance` is not strictly positive and finite.
+    pub fn new(
+        dimension: usize,
+        noise_variance: f64,
+        initial_actions: &[A],
+        seed: u64,
+    ) -> Result<Self, OctopusError> {
+        if initial_actions.is_empty() {
+            return Err(OctopusError::EmptyCollection {
+                collection_name: "initial_actions".to_string(),
+            });
+        }
+        if dimension == 0 {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "dimension".to_string(),
+                value: dimension.to_string(),
+                expected_range: "greater than 0".to_string(),
+            });
+        }
+        if !(noise_variance.is_finite() && noise_variance > 0.0) {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "noise_variance".to_string(),
+                value: noise_variance.to_string(),
+                expected_range: "finite value greater than 0.0".to_string(),
+            });
+        }
+
+        Ok(LinearThompsonPolicy {
+            dimension,
+            noise_variance,
+            posteriors: initial_actions
+                .iter()
+                .map(|action| (action.id(), ArmPosterior::prior(dimension)))
+                .collect(),
+            action_map: ActionStorage::new(initial_actions)?,
+            seed,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+            frozen: false,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the posterior mean of the action's weight vector, or `None` for unknown actions.
+    pub fn posterior_mean(&self, action: &A) -> Option<&Array1<f64>> {
+        self.posteriors.get(&action.id()).map(|posterior| &posterior.mean)
+    }
+
+    /// Flattens the context into its feature vector, checking its length.
+    fn features(&self, context: &C) -> Array1<f64> {
+        let features: Array1<f64> = context.to_ndarray().iter().copied().collect();
+        assert_eq!(
+            features.len(),
+            self.dimension,
+            "context has {} features, expected {}",
+            features.len(),
+            self.dimension
+        );
+        features
+    }
+}
+
+/// Returns the lower-triangular Cholesky factor `L` of a symmetric positive semi-definite matrix.
+///
+/// Pivots that round to zero or below are clamped to zero, so the factor stays finite when the
+/// covariance has collapsed along some direction.
+fn cholesky(matrix: &Array2<f64>) -> Array2<f64> {
+    let n = matrix.nrows();
+    let mut lower = Array2::<f64>::zeros((n, n));
+    for i in 0..n {
+        for j in 0..=i {
+            let partial: f64 = (0..j).map(|k| lower[[i, k]] * lower[[j, k]]).sum();
+            let residual = matrix[[i, j]] - partial;
+            lower[[i, j]] = if i == j {
+                residual.max(0.0).sqrt()
+            } else if lower[[j, j]] > 0.0 {
+                residual / lower[[j, j]]
+            } else {
+                0.0
+            };
+        }
+    }
+    lower
+}
+
+impl<A, R, C> Clone for LinearThompsonPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    fn clone(&self) -> Self {
+        LinearThompsonPolicy {
+            dimension: self.dimension,
+            noise_variance: self.noise_variance,
+            posteriors: self.posteriors.clone(),
+            action_map: self.action_map.clone(),
+            seed: self.seed,
+            rng: Mutex::new(StdRng::seed_from_u64(self.seed)),
+            frozen: self.frozen,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R, C> BanditPolicy<A, R, C> for LinearThompsonPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    fn choose_action(&self, context: &C) -> A {
+        self.choose_action_scored(context).0
+    }
+
+    /// Samples a weight vector from each arm's posterior and returns the arm with the highest
+    /// `x · theta_sampled`, using that value as the score.
+    fn choose_action_scored(&self, context: &C) -> (A, f64) {
+        let features = self.features(context);
+        let mut rng = self.rng.lock().unwrap();
+        // sort action_ids to ensure deterministic output when the seeds are equal
+        let action_ids = self.action_map.sorted_ids();
+        let mut best_action_id = action_ids[0];
+        let mut best_sampled_reward = f64::NEG_INFINITY;
+
+        for action_id in action_ids {
+            let posterior = &self.posteriors[&action_id];
+            let standard_normal: Array1<f64> =
+                (0..self.dimension).map(|_| StandardNormal.sample(&mut *rng)).collect();
+            let theta = &posterior.mean + &cholesky(&posterior.covariance).dot(&standard_normal);
+            let sampled_reward = features.dot(&theta);
+
+            if sampled_reward > best_sampled_reward {
+                best_sampled_reward = sampled_reward;
+                best_action_id = action_id;
+            }
+        }
+
+        (
+            self.action_map.get(&best_action_id).unwrap().clone(),
+            best_sampled_reward,
+        )
+    }
+
+    /// Applies the conjugate Gaussian update to the chosen arm's posterior, as a rank-one
+    /// (Sherman-Morrison) update of its covariance. Does nothing while learning is frozen.
+    fn update(&mut self, context: &C, action: &A, reward: &R) {
+        if self.frozen {
+            return;
+        }
+        let features = self.features(context);
+        let Some(posterior) = self.posteriors.get_mut(&action.id()) else {
+            return;
+        };
+
+        let covariance_x = posterior.covariance.dot(&features);
+        let denominator = self.noise_variance + features.dot(&covariance_x);
+        let residual = reward.value() - features.dot(&posterior.mean);
+
+        posterior.mean.scaled_add(residual / denominator, &covariance_x);
+        let column = covariance_x.view().insert_axis(Axis(1));
+        let row = covariance_x.view().insert_axis(Axis(0));
+        posterior.covariance -= &(column.dot(&row) / denominator);
+    }
+
+    fn set_frozen(&mut self, frozen: bool) {
+        self.frozen = frozen;
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Resets every arm's posterior to the `N(0, I)` prior. The RNG continues its stream; see `reset_rng`.
+    fn reset(&mut self) {
+        for posterior in self.posteriors.values_mut() {
+            *posterior = ArmPosterior::prior(self.dimension);
+        }
+    }
+
+    fn reset_rng(&mut self) {
+        self.rng = Mutex::new(StdRng::seed_from_u64(self.seed));
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.reset_rng();
+    }
+
+    fn action_ids(&self) -> Vec<u32> {
+        self.action_map.sorted_ids()
+    }
+
+    /// Captures each arm's posterior mean and row-major covariance.
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        Ok(PolicyCheckpoint::LinearThompson {
+            means: self
+                .posteriors
+                .iter()
+                .map(|(&action_id, posterior)| (action_id, posterior.mean.to_vec()))
+                .collect(),
+            covariances: self
+                .posteriors
+                .iter()
+                .map(|(&action_id, posterior)| {
+                    (action_id, posterior.covariance.iter().copied().collect())
+                })
+                .collect(),
+            seed: self.seed,
+        })
+    }
+
+    fn restore(&mut self, checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        let PolicyCheckpoint::LinearThompson {
+            means,
+            covariances,
+            seed,
+        } = checkpoint
+        else {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "expected a LinearThompson checkpoint".to_string(),
+            });
+        };
+        if !self.action_map.has_same_ids(means) || !self.action_map.has_same_ids(covariances) {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "checkpoint action ids do not match the policy's actions".to_string(),
+            });
+        }
+
+        let mut posteriors = HashMap::with_capacity(means.len());
+        for (&action_id, mean) in means {
+            let covariance = &covariances[&action_id];
+            if mean.len() != self.dimension || covariance.len() != self.dimension * self.dimension {
+                return Err(OctopusError::InvalidCheckpoint {
+                    reason: format!(
+                        "posterior of action {} does not match dimension {}",
+                        action_id, self.dimension
+                    ),
+                });
+            }
+            posteriors.insert(
+                action_id,
+                ArmPosterior {
+                    mean: Array1::from_vec(mean.clone()),
+                    covariance: Array2::from_shape_vec(
+                        (self.dimension, self.dimension),
+                        covariance.clone(),
+                    )
+                    .expect("covariance length was checked against the dimension"),
+                },
+            );
+        }
+
+        self.posteriors = posteriors;
+        self.seed = *seed;
+        self.rng = Mutex::new(StdRng::seed_from_u64(*seed));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::thompson_sampling::ThompsonSamplingPolicy;
+    use crate::environments::generated_context::GeneratedContextEnvironment;
+    use crate::simulation::simulator::Simulator;
+    use crate::traits::entities::NumericAction;
+    use ndarray::{Array, Ix1};
+    use rand::Rng;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl From<f64> for DummyReward {
+        fn from(value: f64) -> Self {
+            DummyReward(value)
+        }
+    }
+
+    /// Bias term plus one binary segment feature.
+    #[derive(Debug, Clone, PartialEq)]
+    struct SegmentContext {
+        segment: f64,
+    }
+
+    impl Context for SegmentContext {
+        type DimType = Ix1;
+        fn to_ndarray(&self) -> Array<f64, Self::DimType> {
+            Array1::from_vec(vec![1.0, self.segment])
+        }
+    }
+
+    #[test]
+    fn test_linear_thompson_init_invalid_parameters() {
+        let actions = vec![NumericAction::new(0i32, "a0")];
+        let err = LinearThompsonPolicy::<NumericAction<i32>, DummyReward, SegmentContext>::new(
+            0, 1.0, &actions, 42,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, OctopusError::InvalidParameter { ref parameter_name, .. } if parameter_name == "dimension")
+        );
+
+        let err = LinearThompsonPolicy::<NumericAction<i32>, DummyReward, SegmentContext>::new(
+            2, 0.0, &actions, 42,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, OctopusError::InvalidParameter { ref parameter_name, .. } if parameter_name == "noise_variance")
+        );
+
+        let err = LinearThompsonPolicy::<NumericAction<i32>, DummyReward, SegmentContext>::new(
+            2,
+            1.0,
+            &[],
+            42,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::EmptyCollection {
+                collection_name: "initial_actions".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_linear_thompson_update_moves_mean_towards_reward() {
+        let actions = vec![NumericAction::with_id(0, 0i32, "a0")];
+        let mut policy =
+            LinearThompsonPolicy::<NumericAction<i32>, DummyReward, SegmentContext>::new(
+                2, 0.01, &actions, 42,
+            )
+            .unwrap();
+        let context = SegmentContext { segment: 1.0 };
+        for _ in 0..50 {
+            policy.update(&context, &actions[0], &DummyReward(0.6));
+        }
+
+        let features = context.to_ndarray();
+        let predicted = features.dot(policy.posterior_mean(&actions[0]).unwrap());
+        assert!((predicted - 0.6).abs() < 1e-3, "predicted {}", predicted);
+
+        let checkpoint = policy.checkpoint().unwrap();
+        let mut restored =
+            LinearThompsonPolicy::<NumericAction<i32>, DummyReward, SegmentContext>::new(
+                2, 0.01, &actions, 0,
+            )
+            .unwrap();
+        restored.restore(&checkpoint).unwrap();
+        assert_eq!(restored.posteriors, policy.posteriors);
+    }
+
+    #[test]
+    fn test_linear_thompson_beats_context_blind_thompson() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "a0"),
+            NumericAction::with_id(1, 1i32, "a1"),
+        ];
+        // Arm 0 pays 0.8 in segment 0 and 0.2 in segment 1; arm 1 the reverse.
+        let thetas = [[0.8, -0.6], [0.2, 0.6]];
+        let environment = GeneratedContextEnvironment::<_, DummyReward, _, _>::new(
+            |rng: &mut StdRng| SegmentContext {
+                segment: if rng.random_bool(0.5) { 1.0 } else { 0.0 },
+            },
+            move |action: &NumericAction<i32>, context: &SegmentContext| {
+                let theta = thetas[action.id() as usize];
+                theta[0] + theta[1] * context.segment
+            },
+            7,
+        );
+
+        let linear = LinearThompsonPolicy::new(2, 0.05, &actions, 42).unwrap();
+        let blind = ThompsonSamplingPolicy::new(&actions, 42).unwrap();
+        let linear_results =
+            Simulator::new(linear, environment.clone()).run_episode(1000, &actions);
+        let blind_results = Simulator::new(blind, environment).run_episode(1000, &actions);
+
+        let linear_regret = *linear_results.steps_regret.last().unwrap();
+        let blind_regret = *blind_results.steps_regret.last().unwrap();
+        assert!(
+            linear_regret < blind_regret / 2.0,
+            "linear regret {} vs context-blind regret {}",
+            linear_regret,
+            blind_regret
+        );
+    }
+}
@@ -1,7 +1,21 @@
 //! Bandit algorithm implementations.
 //!
-//! This module contains concrete implementations of bandit policies, such as Epsilon-Greedy.
+//! This module contains concrete implementations of bandit policies:
+//!
+//! - Learning policies: Epsilon-Greedy, UCB1, Softmax-UCB, Softmax, Thompson Sampling, Linear
+//!   Thompson Sampling, Perturbed-History Exploration and Successive Elimination.
+//! - Non-learning baselines: round-robin, uniform random selection and an oracle.
+//!
 //! All algorithms implement the BanditPolicy trait and are generic over action, reward, and context types.
 
 pub mod epsilon_greedy;
+pub mod linear_thompson;
+pub mod oracle;
+pub mod perturbed_history;
+pub mod random;
+pub mod round_robin;
+pub mod soft_ucb;
+pub mod softmax;
+pub mod successive_elimination;
 pub mod thompson_sampling;
+pub mod ucb1;
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use crate::traits::entities::{Action, ActionStorage, Context, DummyContext, Reward};
+use crate::traits::policy::{BanditPolicy, PolicyCheckpoint};
+use crate::utils::error::OctopusError;
+
+/// Oracle baseline policy for Multi-Armed Bandit problems.
+///
+/// Knows the true mean reward of every action, given at construction, and always picks the best
+/// one (ties broken by smallest ID). Its regret is zero by construction, so it marks the ceiling
+/// in policy comparisons. Rewards are ignored.
+#[derive(Debug, Clone)]
+pub struct OraclePolicy<A, R, C = DummyContext>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    action_map: ActionStorage<A>,
+    means: HashMap<u32, f64>,
+    best_action_id: u32,
+    _phantom: PhantomData<(R, C)>,
+}
+
+impl<A, R, C> OraclePolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    /// Creates a new OraclePolicy.
+    ///
+    /// * `initial_actions` - Slice of all possible actions.
+    /// * `means` - True mean reward for each action ID.
+    ///
+    /// Returns an error if actions are empty or if an action has no finite mean.
+    pub fn new(initial_actions: &[A], means: HashMap<u32, f64>) -> Result<Self, OctopusError> {
+        if initial_actions.is_empty() {
+            return Err(OctopusError::EmptyCollection {
+                collection_name: "initial_actions".to_string(),
+            });
+        }
+        for action in initial_actions {
+            match means.get(&action.id()) {
+                Some(mean) if mean.is_finite() => {}
+                other => {
+                    return Err(OctopusError::InvalidParameter {
+                        parameter_name: format!("means[{}]", action.id()),
+                        value: other.map_or("missing".to_string(), |mean| mean.to_string()),
+                        expected_range: "finite value".to_string(),
+                    });
+                }
+            }
+        }
+
+        let action_map = ActionStorage::new(initial_actions)?;
+        let best_action_id = action_map
+            .sorted_ids()
+            .into_iter()
+            .fold(None, |best: Option<u32>, action_id| match best {
+                Some(best_id) if means[&best_id] >= means[&action_id] => Some(best_id),
+                _ => Some(action_id),
+            })
+            .unwrap();
+
+        Ok(OraclePolicy {
+            action_map,
+            means,
+            best_action_id,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the true mean configured for the given action ID, if any.
+    pub fn mean(&self, action_id: u32) -> Option<f64> {
+        self.means.get(&action_id).copied()
+    }
+}
+
+impl<A, R, C> BanditPolicy<A, R, C> for OraclePolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    fn choose_action(&self, context: &C) -> A {
+        self.choose_action_scored(context).0
+    }
+
+    /// Returns the action with the highest true mean, scored by that mean.
+    /// Ignores context (non-contextual).
+    fn choose_action_scored(&self, _context: &C) -> (A, f64) {
+        (
+            self.action_map.get(&self.best_action_id).unwrap().clone(),
+            self.means[&self.best_action_id],
+        )
+    }
+
+    /// Ignores the outcome; the oracle already knows the means.
+    fn update(&mut self, _context: &C, _action: &A, _reward: &R) {}
+
+    fn reset(&mut self) {}
+
+    /// Returns the true mean of the action.
+    fn expected_reward(&self, action: &A) -> Option<f64> {
+        self.mean(action.id())
+    }
+
+    /// Returns 1.0 for the best action and 0.0 for every other known action.
+    fn selection_probability(&self, _context: &C, action: &A) -> Option<f64> {
+        self.action_map.contains_key(&action.id()).then(|| {
+            if action.id() == self.best_action_id {
+                1.0
+            } else {
+                0.0
+            }
+        })
+    }
+
+    /// Always 0.0: the oracle is committed to a single arm.
+    fn selection_entropy(&self, _context: &C) -> f64 {
+        0.0
+    }
+
+    fn action_ids(&self) -> Vec<u32> {
+        self.action_map.sorted_ids()
+    }
+
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        Ok(PolicyCheckpoint::Oracle)
+    }
+
+    fn restore(&mut self, checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        match checkpoint {
+            PolicyCheckpoint::Oracle => Ok(()),
+            _ => Err(OctopusError::InvalidCheckpoint {
+                reason: "expected an Oracle checkpoint".to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environments::gaussian::GaussianEnvironment;
+    use crate::simulation::metrics::analyze_results;
+    use crate::simulation::simulator::Simulator;
+    use crate::traits::entities::{DummyContext, NumericAction};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl From<f64> for DummyReward {
+        fn from(value: f64) -> Self {
+            DummyReward(value)
+        }
+    }
+
+    #[test]
+    fn test_oracle_init_requires_every_mean() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+        ];
+        let err = OraclePolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            &actions,
+            [(0, 1.0)].into_iter().collect(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "means[1]".to_string(),
+                value: "missing".to_string(),
+                expected_range: "finite value".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_oracle_regret_stays_near_zero() {
+        let actions: Vec<NumericAction<i32>> =
+            (0..3).map(|id| NumericAction::with_id(id, id as i32, "arm")).collect();
+        let arms = [(0, (0.5, 1.0)), (1, (2.0, 1.0)), (2, (1.5, 1.0))];
+        let env = GaussianEnvironment::new(arms.into_iter().collect(), 42).unwrap();
+        let means: HashMap<u32, f64> = arms.iter().map(|&(id, (mean, _))| (id, mean)).collect();
+        let oracle =
+            OraclePolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(&actions, means)
+                .unwrap();
+        assert_eq!(oracle.choose_action(&DummyContext).id(), 1);
+        assert_eq!(
+            oracle.selection_probability(&DummyContext, &actions[1]),
+            Some(1.0)
+        );
+        assert_eq!(
+            oracle.selection_probability(&DummyContext, &actions[0]),
+            Some(0.0)
+        );
+        assert_eq!(oracle.selection_entropy(&DummyContext), 0.0);
+
+        let mut simulator = Simulator::new(oracle, env);
+        let results = simulator.run_episode(1000, &actions);
+        assert!(results.steps_regret.iter().all(|regret| regret.abs() < 1e-9));
+        // Cumulative regret uses the same true-mean accounting as `steps_regret`.
+        assert!(results.final_simple_regret().abs() < 1e-9);
+        assert!(
+            analyze_results(std::slice::from_ref(&results)).average_cumulative_regret.abs() < 1e-9
+        );
+        // Observed rewards still average to the best mean.
+        assert!((results.cumulative_reward / 1000.0 - 2.0).abs() < 0.2);
+    }
+}
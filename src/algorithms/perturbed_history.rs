@@ -0,0 +1,294 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand_distr::{Binomial, Distribution};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::traits::entities::{Action, ActionStorage, Context, DummyContext, Reward};
+use crate::traits::policy::{BanditPolicy, PolicyCheckpoint};
+use crate::utils::error::OctopusError;
+
+/// Perturbed-History Exploration (PHE) policy for Multi-Armed Bandit problems.
+///
+/// Each step, every arm's observed rewards are augmented with `a` pseudo-rewards drawn from
+/// Bernoulli(0.5), and the arm with the highest perturbed mean `(sum + pseudo_sum) / (n + a)` is
+/// picked (smallest ID on ties). The noise explores without explicit confidence bounds and fades
+/// as an arm's history grows. Rewards are expected in `[0, 1]`.
+#[derive(Debug)]
+pub struct PerturbedHistoryPolicy<A, R, C = DummyContext>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    perturbation_count: u32,
+    counts: HashMap<u32, u64>,
+    sum_rewards: HashMap<u32, f64>,
+    action_map: ActionStorage<A>,
+    seed: u64,
+    rng: Mutex<StdRng>,
+    frozen: bool,
+    _phantom: PhantomData<(R, C)>,
+}
+
+impl<A, R, C> PerturbedHistoryPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    /// Creates a new PerturbedHistoryPolicy with seeded RNG.
+    ///
+    /// * `initial_actions` - Slice of all possible actions.
+    /// * `perturbation_count` - Number `a` of Bernoulli(0.5) pseudo-rewards added per arm (greater than 0).
+    /// * `seed` - Seed for the pseudo-reward sampler.
+    ///
+    /// Returns an error if actions are empty or if `perturbation_count` is 0.
+    pub fn new(
+        initial_actions: &[A],
+        perturbation_count: u32,
+        seed: u64,
+    ) -> Result<Self, OctopusError> {
+        if initial_actions.is_empty() {
+            return Err(OctopusError::EmptyCollection {
+                collection_name: "initial_actions".to_string(),
+            });
+        }
+        if perturbation_count == 0 {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "perturbation_count".to_string(),
+                value: perturbation_count.to_string(),
+                expected_range: "integer greater than 0".to_string(),
+            });
+        }
+
+        Ok(PerturbedHistoryPolicy {
+            perturbation_count,
+            counts: initial_actions.iter().map(|action| (action.id(), 0)).collect(),
+            sum_rewards: initial_actions.iter().map(|action| (action.id(), 0.0)).collect(),
+            action_map: ActionStorage::new(initial_actions)?,
+            seed,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+            frozen: false,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the average reward for the given action ID, or 0.0 if it has never been pulled.
+    fn get_average_reward(&self, action_id: u32) -> f64 {
+        let count = *self.counts.get(&action_id).unwrap_or(&0);
+        if count == 0 {
+            0.0
+        } else {
+            *self.sum_rewards.get(&action_id).unwrap_or(&0.0) / count as f64
+        }
+    }
+}
+
+impl<A, R, C> Clone for PerturbedHistoryPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    fn clone(&self) -> Self {
+        PerturbedHistoryPolicy {
+            perturbation_count: self.perturbation_count,
+            counts: self.counts.clone(),
+            sum_rewards: self.sum_rewards.clone(),
+            action_map: self.action_map.clone(),
+            seed: self.seed,
+            rng: Mutex::new(StdRng::seed_from_u64(self.seed)),
+            frozen: self.frozen,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R, C> BanditPolicy<A, R, C> for PerturbedHistoryPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    fn choose_action(&self, context: &C) -> A {
+        self.choose_action_scored(context).0
+    }
+
+    /// Returns the arm with the highest perturbed mean, using that mean as the score.
+    /// Ignores context (non-contextual).
+    fn choose_action_scored(&self, _context: &C) -> (A, f64) {
+        let pseudo_rewards = Binomial::new(self.perturbation_count as u64, 0.5)
+            .expect("Binomial parameters must be valid.");
+        let mut rng = self.rng.lock().unwrap();
+
+        // sort action_ids to ensure deterministic output when the seeds are equal
+        let action_ids = self.action_map.sorted_ids();
+        let mut best_action_id = action_ids[0];
+        let mut best_score = f64::NEG_INFINITY;
+        for action_id in action_ids {
+            let pseudo_sum = pseudo_rewards.sample(&mut *rng) as f64;
+            let count = self.counts[&action_id] as f64;
+            let score = (self.sum_rewards[&action_id] + pseudo_sum)
+                / (count + self.perturbation_count as f64);
+            if score > best_score {
+                best_score = score;
+                best_action_id = action_id;
+            }
+        }
+        (
+            self.action_map.get(&best_action_id).unwrap().clone(),
+            best_score,
+        )
+    }
+
+    fn update(&mut self, _context: &C, action: &A, reward: &R) {
+        if self.frozen {
+            return;
+        }
+        let action_id = action.id();
+        *self.counts.entry(action_id).or_insert(0) += 1;
+        *self.sum_rewards.entry(action_id).or_insert(0.0) += reward.value();
+    }
+
+    fn set_frozen(&mut self, frozen: bool) {
+        self.frozen = frozen;
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    fn reset(&mut self) {
+        for &action_id in self.action_map.keys() {
+            *self.counts.get_mut(&action_id).unwrap() = 0;
+            *self.sum_rewards.get_mut(&action_id).unwrap() = 0.0;
+        }
+    }
+
+    fn reset_rng(&mut self) {
+        self.rng = Mutex::new(StdRng::seed_from_u64(self.seed));
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.reset_rng();
+    }
+
+    /// Returns the observed average reward of the action (0.0 if never pulled), without perturbation.
+    fn expected_reward(&self, action: &A) -> Option<f64> {
+        self.action_map
+            .contains_key(&action.id())
+            .then(|| self.get_average_reward(action.id()))
+    }
+
+    fn action_ids(&self) -> Vec<u32> {
+        self.action_map.sorted_ids()
+    }
+
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        Ok(PolicyCheckpoint::PerturbedHistory {
+            counts: self.action_map.known_entries(&self.counts),
+            sum_rewards: self.action_map.known_entries(&self.sum_rewards),
+            seed: self.seed,
+        })
+    }
+
+    fn restore(&mut self, checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        let PolicyCheckpoint::PerturbedHistory {
+            counts,
+            sum_rewards,
+            seed,
+        } = checkpoint
+        else {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "expected a PerturbedHistory checkpoint".to_string(),
+            });
+        };
+        if !self.action_map.has_same_ids(counts) || !self.action_map.has_same_ids(sum_rewards) {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "checkpoint action ids do not match the policy's actions".to_string(),
+            });
+        }
+
+        self.counts = counts.clone();
+        self.sum_rewards = sum_rewards.clone();
+        self.seed = *seed;
+        self.rng = Mutex::new(StdRng::seed_from_u64(*seed));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environments::bernoulli::BernoulliEnvironment;
+    use crate::traits::entities::{DummyContext, NumericAction};
+    use crate::traits::environment::Environment;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl From<f64> for DummyReward {
+        fn from(value: f64) -> Self {
+            DummyReward(value)
+        }
+    }
+
+    type TestPolicy = PerturbedHistoryPolicy<NumericAction<i32>, DummyReward, DummyContext>;
+
+    #[test]
+    fn test_perturbed_history_invalid_count() {
+        let actions = vec![NumericAction::with_id(0, 0i32, "A")];
+        let err = TestPolicy::new(&actions, 0, 42).unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "perturbation_count".to_string(),
+                value: "0".to_string(),
+                expected_range: "integer greater than 0".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_perturbed_history_concentrates_on_better_arm() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "weak"),
+            NumericAction::with_id(1, 1i32, "strong"),
+        ];
+        let probabilities = [(0, 0.3), (1, 0.7)];
+        let env = BernoulliEnvironment::<NumericAction<i32>, DummyReward>::new(
+            probabilities.into_iter().collect(),
+            42,
+        )
+        .unwrap();
+        let mut policy = TestPolicy::new(&actions, 10, 7).unwrap();
+        let ctx = DummyContext;
+
+        let num_steps = 3000;
+        let mut late_strong_picks = 0;
+        for step in 0..num_steps {
+            let action = policy.choose_action(&ctx);
+            let reward = env.get_reward(&action, &ctx);
+            policy.update(&ctx, &action, &reward);
+            if step >= num_steps - 1000 && action.id() == 1 {
+                late_strong_picks += 1;
+            }
+        }
+
+        assert!(
+            late_strong_picks > 950,
+            "strong arm picked {} of the last 1000 steps",
+            late_strong_picks
+        );
+        assert!(policy.counts[&1] > policy.counts[&0]);
+    }
+}
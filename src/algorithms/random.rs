@@ -0,0 +1,197 @@
+use rand::SeedableRng;
+use rand::prelude::IndexedRandom;
+use rand::rngs::StdRng;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::traits::entities::{Action, ActionStorage, Context, DummyContext, Reward};
+use crate::traits::policy::{BanditPolicy, PolicyCheckpoint};
+use crate::utils::error::OctopusError;
+
+/// Uniform random baseline policy for Multi-Armed Bandit problems.
+///
+/// Picks an action uniformly at random on every `choose_action` call and ignores rewards.
+/// This is the lower baseline for regret comparisons.
+#[derive(Debug)]
+pub struct RandomPolicy<A, R, C = DummyContext>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    action_map: ActionStorage<A>,
+    seed: u64,
+    rng: Mutex<StdRng>,
+    _phantom: PhantomData<(R, C)>,
+}
+
+impl<A, R, C> RandomPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    /// Creates a new RandomPolicy with seeded RNG.
+    ///
+    /// * `initial_actions` - Slice of all possible actions.
+    /// * `seed` - Seed for the action sampler.
+    ///
+    /// Returns an error if actions are empty.
+    pub fn new(initial_actions: &[A], seed: u64) -> Result<Self, OctopusError> {
+        if initial_actions.is_empty() {
+            return Err(OctopusError::EmptyCollection {
+                collection_name: "initial_actions".to_string(),
+            });
+        }
+        Ok(RandomPolicy {
+            action_map: ActionStorage::new(initial_actions)?,
+            seed,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<A, R, C> Clone for RandomPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    fn clone(&self) -> Self {
+        RandomPolicy {
+            action_map: self.action_map.clone(),
+            seed: self.seed,
+            rng: Mutex::new(StdRng::seed_from_u64(self.seed)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R, C> BanditPolicy<A, R, C> for RandomPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    /// Picks an action uniformly at random. Ignores context (non-contextual).
+    fn choose_action(&self, _context: &C) -> A {
+        let action_ids = self.action_map.sorted_ids();
+        let mut rng = self.rng.lock().unwrap();
+        let chosen_id = action_ids.choose(&mut *rng).unwrap();
+        self.action_map.get(chosen_id).unwrap().clone()
+    }
+
+    /// Ignores the outcome; the baseline does not learn.
+    fn update(&mut self, _context: &C, _action: &A, _reward: &R) {}
+
+    /// There is no learned state to reset. The RNG continues its stream; see `reset_rng`.
+    fn reset(&mut self) {}
+
+    fn reset_rng(&mut self) {
+        self.rng = Mutex::new(StdRng::seed_from_u64(self.seed));
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.reset_rng();
+    }
+
+    /// Returns `1 / K` for every known action.
+    fn selection_probability(&self, _context: &C, action: &A) -> Option<f64> {
+        self.action_map
+            .contains_key(&action.id())
+            .then(|| 1.0 / self.action_map.len() as f64)
+    }
+
+    /// Returns `ln(K)`, the entropy of a uniform choice.
+    fn selection_entropy(&self, _context: &C) -> f64 {
+        (self.action_map.len() as f64).ln()
+    }
+
+    fn action_ids(&self) -> Vec<u32> {
+        self.action_map.sorted_ids()
+    }
+
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        Ok(PolicyCheckpoint::Random { seed: self.seed })
+    }
+
+    fn restore(&mut self, checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        let PolicyCheckpoint::Random { seed } = checkpoint else {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "expected a Random checkpoint".to_string(),
+            });
+        };
+        self.seed = *seed;
+        self.rng = Mutex::new(StdRng::seed_from_u64(*seed));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::entities::{DummyContext, NumericAction};
+    use std::collections::HashMap;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    fn build_policy(seed: u64) -> RandomPolicy<NumericAction<i32>, DummyReward, DummyContext> {
+        let actions: Vec<NumericAction<i32>> =
+            (0..4).map(|id| NumericAction::with_id(id, id as i32, "arm")).collect();
+        RandomPolicy::new(&actions, seed).unwrap()
+    }
+
+    #[test]
+    fn test_random_policy_is_roughly_uniform() {
+        let policy = build_policy(42);
+        let ctx = DummyContext;
+        let num_trials = 40_000;
+
+        let mut counts: HashMap<u32, usize> = HashMap::new();
+        for _ in 0..num_trials {
+            *counts.entry(policy.choose_action(&ctx).id()).or_insert(0) += 1;
+        }
+        for action_id in 0..4 {
+            let share = counts[&action_id] as f64 / num_trials as f64;
+            assert!(
+                (share - 0.25).abs() < 0.02,
+                "arm {} share {}",
+                action_id,
+                share
+            );
+        }
+    }
+
+    #[test]
+    fn test_random_policy_is_reproducible() {
+        let ctx = DummyContext;
+        let draw =
+            |policy: &RandomPolicy<NumericAction<i32>, DummyReward, DummyContext>| -> Vec<u32> {
+                (0..50).map(|_| policy.choose_action(&ctx).id()).collect()
+            };
+
+        let first = draw(&build_policy(7));
+        assert_eq!(draw(&build_policy(7)), first);
+        assert_ne!(draw(&build_policy(8)), first);
+    }
+
+    #[test]
+    fn test_random_policy_selection_is_uniform() {
+        let policy = build_policy(42);
+        let ctx = DummyContext;
+        let arm = NumericAction::with_id(2, 2i32, "arm");
+        assert_eq!(policy.selection_probability(&ctx, &arm), Some(0.25));
+        let unknown = NumericAction::with_id(99, 99i32, "unknown");
+        assert_eq!(policy.selection_probability(&ctx, &unknown), None);
+        assert!((policy.selection_entropy(&ctx) - 4f64.ln()).abs() < 1e-12);
+    }
+}
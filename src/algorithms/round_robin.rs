@@ -0,0 +1,153 @@
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::traits::entities::{Action, ActionStorage, Context, DummyContext, Reward};
+use crate::traits::policy::{BanditPolicy, PolicyCheckpoint};
+use crate::utils::error::OctopusError;
+
+/// Round-robin baseline policy for Multi-Armed Bandit problems.
+///
+/// Cycles through the actions in ascending ID order, one per `choose_action` call, and ignores
+/// rewards. Every arm is explored equally, which makes it a reference point for benchmarking.
+#[derive(Debug)]
+pub struct RoundRobinPolicy<A, R, C = DummyContext>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    action_map: ActionStorage<A>,
+    step: Mutex<u64>,
+    _phantom: PhantomData<(R, C)>,
+}
+
+impl<A, R, C> RoundRobinPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    /// Creates a new RoundRobinPolicy.
+    ///
+    /// * `initial_actions` - Slice of all possible actions.
+    ///
+    /// Returns an error if actions are empty.
+    pub fn new(initial_actions: &[A]) -> Result<Self, OctopusError> {
+        if initial_actions.is_empty() {
+            return Err(OctopusError::EmptyCollection {
+                collection_name: "initial_actions".to_string(),
+            });
+        }
+        Ok(RoundRobinPolicy {
+            action_map: ActionStorage::new(initial_actions)?,
+            step: Mutex::new(0),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<A, R, C> Clone for RoundRobinPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    fn clone(&self) -> Self {
+        RoundRobinPolicy {
+            action_map: self.action_map.clone(),
+            step: Mutex::new(*self.step.lock().unwrap()),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R, C> BanditPolicy<A, R, C> for RoundRobinPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    /// Returns the next action in ID order, wrapping around after the last one.
+    /// Ignores context (non-contextual).
+    fn choose_action(&self, _context: &C) -> A {
+        let action_ids = self.action_map.sorted_ids();
+        let mut step = self.step.lock().unwrap();
+        let chosen_id = action_ids[(*step % action_ids.len() as u64) as usize];
+        *step += 1;
+        self.action_map.get(&chosen_id).unwrap().clone()
+    }
+
+    /// Ignores the outcome; the baseline does not learn.
+    fn update(&mut self, _context: &C, _action: &A, _reward: &R) {}
+
+    /// Restarts the cycle from the smallest action ID.
+    fn reset(&mut self) {
+        *self.step.lock().unwrap() = 0;
+    }
+
+    fn action_ids(&self) -> Vec<u32> {
+        self.action_map.sorted_ids()
+    }
+
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        Ok(PolicyCheckpoint::RoundRobin {
+            step: *self.step.lock().unwrap(),
+        })
+    }
+
+    fn restore(&mut self, checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        let PolicyCheckpoint::RoundRobin { step } = checkpoint else {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "expected a RoundRobin checkpoint".to_string(),
+            });
+        };
+        *self.step.lock().unwrap() = *step;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::entities::{DummyContext, NumericAction};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_round_robin_visits_each_arm_once_per_cycle() {
+        let actions = vec![
+            NumericAction::with_id(7, 0i32, "A"),
+            NumericAction::with_id(3, 1i32, "B"),
+            NumericAction::with_id(5, 2i32, "C"),
+        ];
+        let mut policy =
+            RoundRobinPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(&actions)
+                .unwrap();
+        let ctx = DummyContext;
+
+        for _cycle in 0..3 {
+            let mut visited: Vec<u32> = Vec::new();
+            for _ in 0..actions.len() {
+                let action = policy.choose_action(&ctx);
+                policy.update(&ctx, &action, &DummyReward(1.0));
+                visited.push(action.id());
+            }
+            assert_eq!(visited, vec![3, 5, 7]);
+        }
+
+        policy.choose_action(&ctx);
+        assert_eq!(
+            policy.checkpoint().unwrap(),
+            PolicyCheckpoint::RoundRobin { step: 10 }
+        );
+        policy.reset();
+        assert_eq!(policy.choose_action(&ctx).id(), 3);
+    }
+}
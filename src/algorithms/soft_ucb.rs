@@ -0,0 +1,376 @@
+use rand::SeedableRng;
+use rand::distr::Distribution;
+use rand::distr::weighted::WeightedIndex;
+use rand::rngs::StdRng;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::algorithms::ucb1::ucb_score;
+use crate::traits::entities::{Action, ActionStorage, Context, DummyContext, Reward};
+use crate::traits::policy::{BanditPolicy, PolicyCheckpoint};
+use crate::utils::error::OctopusError;
+
+/// Softmax-over-UCB policy for Multi-Armed Bandit problems.
+///
+/// Computes the UCB1 score `mean_i + c * sqrt(ln(t) / n_i)` for each action, then samples an action
+/// with probability proportional to `exp(ucb_i / tau)` instead of taking a hard argmax.
+/// Low `tau` approaches standard UCB, high `tau` spreads selection across actions.
+/// Actions that have never been pulled are selected first, in ID order.
+#[derive(Debug)]
+pub struct SoftUCBPolicy<A, R, C = DummyContext>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    tau: f64,
+    exploration: f64,
+    counts: HashMap<u32, u64>,
+    sum_rewards: HashMap<u32, f64>,
+    action_map: ActionStorage<A>,
+    total_pulls: u64,
+    seed: u64,
+    rng: Mutex<StdRng>,
+    frozen: bool,
+    _phantom: PhantomData<(R, C)>,
+}
+
+impl<A, R, C> SoftUCBPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    /// Creates a new SoftUCBPolicy with seeded RNG.
+    ///
+    /// * `initial_actions` - Slice of all possible actions.
+    /// * `tau` - Softmax temperature (greater than 0.0).
+    /// * `exploration` - UCB exploration constant `c` (0.0 or greater).
+    /// * `seed` - Seed for the softmax sampler.
+    ///
+    /// Returns an error if actions are empty or if a parameter is out of bounds.
+    pub fn new(
+        initial_actions: &[A],
+        tau: f64,
+        exploration: f64,
+        seed: u64,
+    ) -> Result<Self, OctopusError> {
+        if initial_actions.is_empty() {
+            return Err(OctopusError::EmptyCollection {
+                collection_name: "initial_actions".to_string(),
+            });
+        }
+        if !(tau.is_finite() && tau > 0.0) {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "tau".to_string(),
+                value: tau.to_string(),
+                expected_range: "finite value greater than 0.0".to_string(),
+            });
+        }
+        if !(exploration.is_finite() && exploration >= 0.0) {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "exploration".to_string(),
+                value: exploration.to_string(),
+                expected_range: "finite value of 0.0 or greater".to_string(),
+            });
+        }
+
+        Ok(SoftUCBPolicy {
+            tau,
+            exploration,
+            counts: initial_actions.iter().map(|action| (action.id(), 0)).collect(),
+            sum_rewards: initial_actions.iter().map(|action| (action.id(), 0.0)).collect(),
+            action_map: ActionStorage::new(initial_actions)?,
+            total_pulls: 0,
+            seed,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+            frozen: false,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the UCB score of the given action ID, or infinity if it has never been pulled.
+    fn ucb_score(&self, action_id: u32) -> f64 {
+        ucb_score(
+            *self.counts.get(&action_id).unwrap_or(&0),
+            *self.sum_rewards.get(&action_id).unwrap_or(&0.0),
+            self.total_pulls,
+            self.exploration,
+        )
+    }
+}
+
+impl<A, R, C> Clone for SoftUCBPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    fn clone(&self) -> Self {
+        SoftUCBPolicy {
+            tau: self.tau,
+            exploration: self.exploration,
+            counts: self.counts.clone(),
+            sum_rewards: self.sum_rewards.clone(),
+            action_map: self.action_map.clone(),
+            total_pulls: self.total_pulls,
+            seed: self.seed,
+            rng: Mutex::new(StdRng::seed_from_u64(self.seed)),
+            frozen: self.frozen,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R, C> BanditPolicy<A, R, C> for SoftUCBPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    fn choose_action(&self, context: &C) -> A {
+        self.choose_action_scored(context).0
+    }
+
+    /// Samples an action from the softmax over UCB scores and returns its UCB score.
+    /// Ignores context (non-contextual).
+    fn choose_action_scored(&self, _context: &C) -> (A, f64) {
+        let action_ids = self.action_map.sorted_ids();
+        let scores: Vec<f64> = action_ids.iter().map(|&id| self.ucb_score(id)).collect();
+
+        let chosen_index = match scores.iter().position(|score| score.is_infinite()) {
+            Some(unpulled_index) => unpulled_index,
+            None => {
+                // Subtract the max score before exponentiating for numerical stability.
+                let max_score = scores.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                let weights = scores.iter().map(|score| ((score - max_score) / self.tau).exp());
+                let softmax = WeightedIndex::new(weights)
+                    .expect("Softmax weights must be positive and finite.");
+                let mut rng = self.rng.lock().unwrap();
+                softmax.sample(&mut *rng)
+            }
+        };
+
+        let chosen_id = action_ids[chosen_index];
+        (
+            self.action_map.get(&chosen_id).unwrap().clone(),
+            scores[chosen_index],
+        )
+    }
+
+    /// Records the reward. Non-finite rewards are ignored, as they would make every later score NaN.
+    fn update(&mut self, _context: &C, action: &A, reward: &R) {
+        if self.frozen || !reward.value().is_finite() {
+            return;
+        }
+        let action_id = action.id();
+        *self.counts.entry(action_id).or_insert(0) += 1;
+        *self.sum_rewards.entry(action_id).or_insert(0.0) += reward.value();
+        self.total_pulls += 1;
+    }
+
+    fn set_frozen(&mut self, frozen: bool) {
+        self.frozen = frozen;
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    fn reset(&mut self) {
+        self.total_pulls = 0;
+        for &action_id in self.action_map.keys() {
+            *self.counts.get_mut(&action_id).unwrap() = 0;
+            *self.sum_rewards.get_mut(&action_id).unwrap() = 0.0;
+        }
+    }
+
+    fn reset_rng(&mut self) {
+        self.rng = Mutex::new(StdRng::seed_from_u64(self.seed));
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.reset_rng();
+    }
+
+    /// Returns the average reward of the action (0.0 if never pulled), without the UCB bonus.
+    fn expected_reward(&self, action: &A) -> Option<f64> {
+        let count = *self.counts.get(&action.id())?;
+        let sum_reward = *self.sum_rewards.get(&action.id())?;
+        Some(if count == 0 {
+            0.0
+        } else {
+            sum_reward / count as f64
+        })
+    }
+
+    fn action_ids(&self) -> Vec<u32> {
+        self.action_map.sorted_ids()
+    }
+
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        Ok(PolicyCheckpoint::SoftUCB {
+            counts: self.action_map.known_entries(&self.counts),
+            sum_rewards: self.action_map.known_entries(&self.sum_rewards),
+            total_pulls: self.total_pulls,
+            seed: self.seed,
+        })
+    }
+
+    fn restore(&mut self, checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        let PolicyCheckpoint::SoftUCB {
+            counts,
+            sum_rewards,
+            total_pulls,
+            seed,
+        } = checkpoint
+        else {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "expected a SoftUCB checkpoint".to_string(),
+            });
+        };
+        if !self.action_map.has_same_ids(counts) || !self.action_map.has_same_ids(sum_rewards) {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "checkpoint action ids do not match the policy's actions".to_string(),
+            });
+        }
+
+        self.counts = counts.clone();
+        self.sum_rewards = sum_rewards.clone();
+        self.total_pulls = *total_pulls;
+        self.seed = *seed;
+        self.rng = Mutex::new(StdRng::seed_from_u64(*seed));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::entities::{DummyContext, NumericAction};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    fn trained_policy(tau: f64) -> SoftUCBPolicy<NumericAction<i32>, DummyReward, DummyContext> {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+            NumericAction::with_id(2, 2i32, "C"),
+        ];
+        let mut policy = SoftUCBPolicy::new(&actions, tau, 1.0, 42).unwrap();
+        let ctx = DummyContext;
+        for (action, reward) in actions.iter().zip([0.2, 0.5, 0.8]) {
+            for _ in 0..10 {
+                policy.update(&ctx, action, &DummyReward(reward));
+            }
+        }
+        policy
+    }
+
+    fn selection_counts(
+        policy: &SoftUCBPolicy<NumericAction<i32>, DummyReward, DummyContext>,
+    ) -> HashMap<u32, u64> {
+        let mut counts = HashMap::new();
+        for _ in 0..1000 {
+            *counts.entry(policy.choose_action(&DummyContext).id()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn test_soft_ucb_init_invalid_params() {
+        let actions = vec![NumericAction::with_id(0, 0i32, "A")];
+        let err = SoftUCBPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            &actions, 0.0, 1.0, 42,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "tau".to_string(),
+                value: "0".to_string(),
+                expected_range: "finite value greater than 0.0".to_string(),
+            }
+        );
+
+        let empty: Vec<NumericAction<i32>> = vec![];
+        let err = SoftUCBPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            &empty, 1.0, 1.0, 42,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::EmptyCollection {
+                collection_name: "initial_actions".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_soft_ucb_pulls_unpulled_arms_first() {
+        let actions = vec![
+            NumericAction::with_id(4, 0i32, "A"),
+            NumericAction::with_id(2, 1i32, "B"),
+        ];
+        let mut policy = SoftUCBPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            &actions, 1.0, 1.0, 42,
+        )
+        .unwrap();
+        let ctx = DummyContext;
+
+        let (first, score) = policy.choose_action_scored(&ctx);
+        assert_eq!(first.id(), 2);
+        assert!(score.is_infinite());
+        policy.update(&ctx, &first, &DummyReward(1.0));
+        assert_eq!(policy.choose_action(&ctx).id(), 4);
+    }
+
+    #[test]
+    fn test_soft_ucb_low_tau_approaches_argmax() {
+        let policy = trained_policy(1e-3);
+        let argmax_id = policy
+            .action_ids()
+            .into_iter()
+            .max_by(|a, b| policy.ucb_score(*a).partial_cmp(&policy.ucb_score(*b)).unwrap())
+            .unwrap();
+
+        let counts = selection_counts(&policy);
+        assert_eq!(*counts.get(&argmax_id).unwrap(), 1000);
+    }
+
+    #[test]
+    fn test_soft_ucb_high_tau_spreads_selection() {
+        let policy = trained_policy(100.0);
+        let counts = selection_counts(&policy);
+        for action_id in policy.action_ids() {
+            // Close to uniform: each arm should get a substantial share of 1000 picks.
+            assert!(
+                *counts.get(&action_id).unwrap_or(&0) > 250,
+                "counts: {:?}",
+                counts
+            );
+        }
+    }
+
+    #[test]
+    fn test_soft_ucb_ignores_non_finite_rewards() {
+        let mut policy = trained_policy(0.1);
+        let before = policy.checkpoint().unwrap();
+        let action = NumericAction::with_id(1, 1i32, "B");
+        for reward in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            policy.update(&DummyContext, &action, &DummyReward(reward));
+        }
+        assert_eq!(policy.checkpoint().unwrap(), before);
+        // Sampling still works instead of panicking on NaN weights.
+        selection_counts(&policy);
+    }
+}
@@ -0,0 +1,395 @@
+use rand::SeedableRng;
+use rand::distr::Distribution;
+use rand::distr::weighted::WeightedIndex;
+use rand::rngs::StdRng;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::traits::entities::{Action, ActionStorage, Context, DummyContext, Reward};
+use crate::traits::policy::{BanditPolicy, PolicyCheckpoint};
+use crate::utils::error::OctopusError;
+
+/// Temperature schedule of a `SoftmaxPolicy`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TemperatureSchedule {
+    /// Keep the temperature fixed at `tau`.
+    Constant(f64),
+    /// Anneal the temperature as `tau0 / ln(total_pulls + 2)`, concentrating on the best action over time.
+    Annealed(f64),
+}
+
+/// Softmax (Boltzmann) policy for Multi-Armed Bandit problems.
+///
+/// Samples an action with probability proportional to `exp(mean_i / tau)`, where `mean_i` is the
+/// action's average reward (0.0 if never pulled) and `tau` follows the configured `TemperatureSchedule`.
+/// Low `tau` approaches greedy selection, high `tau` spreads selection across actions.
+#[derive(Debug)]
+pub struct SoftmaxPolicy<A, R, C = DummyContext>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    schedule: TemperatureSchedule,
+    counts: HashMap<u32, u64>,
+    sum_rewards: HashMap<u32, f64>,
+    action_map: ActionStorage<A>,
+    total_pulls: u64,
+    seed: u64,
+    rng: Mutex<StdRng>,
+    frozen: bool,
+    _phantom: PhantomData<(R, C)>,
+}
+
+impl<A, R, C> SoftmaxPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    /// Creates a new SoftmaxPolicy with seeded RNG.
+    ///
+    /// * `initial_actions` - Slice of all possible actions.
+    /// * `schedule` - Temperature schedule; its `tau` or `tau0` must be greater than 0.0.
+    /// * `seed` - Seed for the softmax sampler.
+    ///
+    /// Returns an error if actions are empty or if the temperature is out of bounds.
+    pub fn new(
+        initial_actions: &[A],
+        schedule: TemperatureSchedule,
+        seed: u64,
+    ) -> Result<Self, OctopusError> {
+        if initial_actions.is_empty() {
+            return Err(OctopusError::EmptyCollection {
+                collection_name: "initial_actions".to_string(),
+            });
+        }
+        let (parameter_name, tau) = match schedule {
+            TemperatureSchedule::Constant(tau) => ("tau", tau),
+            TemperatureSchedule::Annealed(tau0) => ("tau0", tau0),
+        };
+        if !(tau.is_finite() && tau > 0.0) {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: parameter_name.to_string(),
+                value: tau.to_string(),
+                expected_range: "finite value greater than 0.0".to_string(),
+            });
+        }
+
+        Ok(SoftmaxPolicy {
+            schedule,
+            counts: initial_actions.iter().map(|action| (action.id(), 0)).collect(),
+            sum_rewards: initial_actions.iter().map(|action| (action.id(), 0.0)).collect(),
+            action_map: ActionStorage::new(initial_actions)?,
+            total_pulls: 0,
+            seed,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+            frozen: false,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the temperature used for the next selection.
+    pub fn temperature(&self) -> f64 {
+        match self.schedule {
+            TemperatureSchedule::Constant(tau) => tau,
+            TemperatureSchedule::Annealed(tau0) => tau0 / ((self.total_pulls + 2) as f64).ln(),
+        }
+    }
+
+    /// Returns the average reward for the given action ID, or 0.0 if it has never been pulled.
+    fn get_average_reward(&self, action_id: u32) -> f64 {
+        let count = *self.counts.get(&action_id).unwrap_or(&0);
+        if count == 0 {
+            0.0
+        } else {
+            *self.sum_rewards.get(&action_id).unwrap_or(&0.0) / count as f64
+        }
+    }
+
+    /// Returns the softmax selection probability of each action, in ascending ID order.
+    fn probabilities(&self) -> Vec<(u32, f64)> {
+        let action_ids = self.action_map.sorted_ids();
+        let means: Vec<f64> = action_ids.iter().map(|&id| self.get_average_reward(id)).collect();
+        // Subtract the max mean before exponentiating for numerical stability.
+        let max_mean = means.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let tau = self.temperature();
+        let weights: Vec<f64> = means.iter().map(|mean| ((mean - max_mean) / tau).exp()).collect();
+        let total: f64 = weights.iter().sum();
+        action_ids.into_iter().zip(weights.into_iter().map(|w| w / total)).collect()
+    }
+}
+
+impl<A, R, C> Clone for SoftmaxPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    fn clone(&self) -> Self {
+        SoftmaxPolicy {
+            schedule: self.schedule,
+            counts: self.counts.clone(),
+            sum_rewards: self.sum_rewards.clone(),
+            action_map: self.action_map.clone(),
+            total_pulls: self.total_pulls,
+            seed: self.seed,
+            rng: Mutex::new(StdRng::seed_from_u64(self.seed)),
+            frozen: self.frozen,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R, C> BanditPolicy<A, R, C> for SoftmaxPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    fn choose_action(&self, context: &C) -> A {
+        self.choose_action_scored(context).0
+    }
+
+    /// Samples an action from the softmax over average rewards and returns its average reward.
+    /// Ignores context (non-contextual).
+    fn choose_action_scored(&self, _context: &C) -> (A, f64) {
+        let probabilities = self.probabilities();
+        let softmax = WeightedIndex::new(probabilities.iter().map(|&(_, p)| p))
+            .expect("Softmax weights must be positive and finite.");
+        let chosen_index = {
+            let mut rng = self.rng.lock().unwrap();
+            softmax.sample(&mut *rng)
+        };
+
+        let chosen_id = probabilities[chosen_index].0;
+        (
+            self.action_map.get(&chosen_id).unwrap().clone(),
+            self.get_average_reward(chosen_id),
+        )
+    }
+
+    /// Records the reward. Non-finite rewards are ignored, as they would make every later weight NaN.
+    fn update(&mut self, _context: &C, action: &A, reward: &R) {
+        if self.frozen || !reward.value().is_finite() {
+            return;
+        }
+        let action_id = action.id();
+        *self.counts.entry(action_id).or_insert(0) += 1;
+        *self.sum_rewards.entry(action_id).or_insert(0.0) += reward.value();
+        self.total_pulls += 1;
+    }
+
+    fn set_frozen(&mut self, frozen: bool) {
+        self.frozen = frozen;
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Resets all statistics, which also restarts an annealed temperature schedule.
+    fn reset(&mut self) {
+        self.total_pulls = 0;
+        for &action_id in self.action_map.keys() {
+            *self.counts.get_mut(&action_id).unwrap() = 0;
+            *self.sum_rewards.get_mut(&action_id).unwrap() = 0.0;
+        }
+    }
+
+    fn reset_rng(&mut self) {
+        self.rng = Mutex::new(StdRng::seed_from_u64(self.seed));
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.reset_rng();
+    }
+
+    /// Returns the average reward of the action (0.0 if never pulled).
+    fn expected_reward(&self, action: &A) -> Option<f64> {
+        self.action_map
+            .contains_key(&action.id())
+            .then(|| self.get_average_reward(action.id()))
+    }
+
+    /// Returns the softmax weight of the action at the current temperature.
+    fn selection_probability(&self, _context: &C, action: &A) -> Option<f64> {
+        self.probabilities()
+            .into_iter()
+            .find(|&(action_id, _)| action_id == action.id())
+            .map(|(_, probability)| probability)
+    }
+
+    fn selection_entropy(&self, _context: &C) -> f64 {
+        self.probabilities()
+            .into_iter()
+            .filter(|&(_, p)| p > 0.0)
+            .map(|(_, p)| -p * p.ln())
+            .sum()
+    }
+
+    fn action_ids(&self) -> Vec<u32> {
+        self.action_map.sorted_ids()
+    }
+
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        Ok(PolicyCheckpoint::Softmax {
+            counts: self.action_map.known_entries(&self.counts),
+            sum_rewards: self.action_map.known_entries(&self.sum_rewards),
+            total_pulls: self.total_pulls,
+            seed: self.seed,
+        })
+    }
+
+    fn restore(&mut self, checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        let PolicyCheckpoint::Softmax {
+            counts,
+            sum_rewards,
+            total_pulls,
+            seed,
+        } = checkpoint
+        else {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "expected a Softmax checkpoint".to_string(),
+            });
+        };
+        if !self.action_map.has_same_ids(counts) || !self.action_map.has_same_ids(sum_rewards) {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "checkpoint action ids do not match the policy's actions".to_string(),
+            });
+        }
+
+        self.counts = counts.clone();
+        self.sum_rewards = sum_rewards.clone();
+        self.total_pulls = *total_pulls;
+        self.seed = *seed;
+        self.rng = Mutex::new(StdRng::seed_from_u64(*seed));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::entities::{DummyContext, NumericAction};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    type TestPolicy = SoftmaxPolicy<NumericAction<i32>, DummyReward, DummyContext>;
+
+    fn actions() -> Vec<NumericAction<i32>> {
+        vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+            NumericAction::with_id(2, 2i32, "C"),
+        ]
+    }
+
+    /// Pulls every arm `pulls_per_arm` times with fixed rewards, so averages stay constant.
+    fn train(policy: &mut TestPolicy, pulls_per_arm: usize) {
+        for (action, reward) in actions().iter().zip([0.2, 0.5, 0.8]) {
+            for _ in 0..pulls_per_arm {
+                policy.update(&DummyContext, action, &DummyReward(reward));
+            }
+        }
+    }
+
+    #[test]
+    fn test_softmax_init_invalid_params() {
+        let err = TestPolicy::new(&actions(), TemperatureSchedule::Annealed(-1.0), 42).unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "tau0".to_string(),
+                value: "-1".to_string(),
+                expected_range: "finite value greater than 0.0".to_string(),
+            }
+        );
+
+        let err = TestPolicy::new(&[], TemperatureSchedule::Constant(1.0), 42).unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::EmptyCollection {
+                collection_name: "initial_actions".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_softmax_annealed_entropy_decreases() {
+        let mut policy =
+            TestPolicy::new(&actions(), TemperatureSchedule::Annealed(0.5), 42).unwrap();
+        let mut previous_entropy = f64::INFINITY;
+        for _ in 0..5 {
+            train(&mut policy, 100);
+            let entropy = policy.selection_entropy(&DummyContext);
+            assert!(
+                entropy < previous_entropy,
+                "{} >= {}",
+                entropy,
+                previous_entropy
+            );
+            previous_entropy = entropy;
+        }
+
+        // With a constant temperature and unchanged averages, entropy stays put.
+        let mut constant =
+            TestPolicy::new(&actions(), TemperatureSchedule::Constant(0.5), 42).unwrap();
+        train(&mut constant, 100);
+        let entropy = constant.selection_entropy(&DummyContext);
+        train(&mut constant, 400);
+        assert!((constant.selection_entropy(&DummyContext) - entropy).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_softmax_selection_probability_sums_to_one() {
+        let mut policy =
+            TestPolicy::new(&actions(), TemperatureSchedule::Constant(0.5), 42).unwrap();
+        let uniform = policy.selection_probability(&DummyContext, &actions()[0]).unwrap();
+        assert!((uniform - 1.0 / 3.0).abs() < 1e-12);
+
+        train(&mut policy, 10);
+        let probabilities: Vec<f64> = actions()
+            .iter()
+            .map(|action| policy.selection_probability(&DummyContext, action).unwrap())
+            .collect();
+        assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!(probabilities[0] < probabilities[1] && probabilities[1] < probabilities[2]);
+
+        let unknown = NumericAction::with_id(99, 99i32, "unknown");
+        assert_eq!(policy.selection_probability(&DummyContext, &unknown), None);
+    }
+
+    #[test]
+    fn test_softmax_prefers_best_arm() {
+        let mut policy =
+            TestPolicy::new(&actions(), TemperatureSchedule::Constant(0.05), 42).unwrap();
+        train(&mut policy, 10);
+        let best_picks =
+            (0..1000).filter(|_| policy.choose_action(&DummyContext).id() == 2).count();
+        assert!(best_picks > 950, "best arm picked {} times", best_picks);
+    }
+
+    #[test]
+    fn test_softmax_ignores_non_finite_rewards() {
+        let mut policy =
+            TestPolicy::new(&actions(), TemperatureSchedule::Constant(0.5), 42).unwrap();
+        train(&mut policy, 10);
+        let before = policy.checkpoint().unwrap();
+        for reward in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            policy.update(&DummyContext, &actions()[1], &DummyReward(reward));
+        }
+        assert_eq!(policy.checkpoint().unwrap(), before);
+        // Sampling still works instead of panicking on NaN weights.
+        policy.choose_action(&DummyContext);
+    }
+}
@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::traits::entities::{Action, ActionStorage, Context, DummyContext, Reward};
+use crate::traits::policy::{BanditPolicy, PolicyCheckpoint};
+use crate::utils::error::OctopusError;
+
+/// Successive Elimination policy for fixed-confidence best-arm identification.
+///
+/// Keeps an active set of arms and pulls them round-robin in ascending ID order. Once every active
+/// arm has been pulled, an arm is eliminated as soon as its upper confidence bound falls below the
+/// lower confidence bound of another active arm; the last remaining arm is pulled exclusively.
+/// Confidence bounds use the Hoeffding radius `sqrt(ln(4 * K * n^2 / delta) / (2 * n))` for an arm
+/// pulled `n` times among `K` arms, so rewards are expected in `[0, 1]`.
+#[derive(Debug)]
+pub struct SuccessiveEliminationPolicy<A, R, C = DummyContext>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    delta: f64,
+    counts: HashMap<u32, u64>,
+    sum_rewards: HashMap<u32, f64>,
+    active_ids: Vec<u32>,
+    action_map: ActionStorage<A>,
+    step: Mutex<u64>,
+    frozen: bool,
+    _phantom: PhantomData<(R, C)>,
+}
+
+impl<A, R, C> SuccessiveEliminationPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    /// Creates a new SuccessiveEliminationPolicy with every arm active.
+    ///
+    /// * `delta` - Probability of eliminating the best arm (0.0 to 1.0, exclusive).
+    /// * `initial_actions` - Slice of all possible actions.
+    ///
+    /// Returns an error if `delta` is out of range or actions are empty.
+    pub fn new(delta: f64, initial_actions: &[A]) -> Result<Self, OctopusError> {
+        if !(delta > 0.0 && delta < 1.0) {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "delta".to_string(),
+                value: delta.to_string(),
+                expected_range: "0.0 to 1.0 exclusive".to_string(),
+            });
+        }
+        if initial_actions.is_empty() {
+            return Err(OctopusError::EmptyCollection {
+                collection_name: "initial_actions".to_string(),
+            });
+        }
+
+        let action_map = ActionStorage::new(initial_actions)?;
+        Ok(SuccessiveEliminationPolicy {
+            delta,
+            counts: action_map.keys().map(|&action_id| (action_id, 0)).collect(),
+            sum_rewards: action_map.keys().map(|&action_id| (action_id, 0.0)).collect(),
+            active_ids: action_map.sorted_ids(),
+            action_map,
+            step: Mutex::new(0),
+            frozen: false,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the IDs of the arms not yet eliminated, sorted ascending.
+    pub fn active_action_ids(&self) -> &[u32] {
+        &self.active_ids
+    }
+
+    /// Returns the surviving arm once all others have been eliminated.
+    pub fn identified_best(&self) -> Option<&A> {
+        match self.active_ids.as_slice() {
+            [action_id] => self.action_map.get(action_id),
+            _ => None,
+        }
+    }
+
+    /// Returns the average reward for the given action ID, or 0.0 if it has not been pulled yet.
+    fn get_average_reward(&self, action_id: u32) -> f64 {
+        let count = *self.counts.get(&action_id).unwrap_or(&0);
+        if count == 0 {
+            0.0
+        } else {
+            self.sum_rewards[&action_id] / count as f64
+        }
+    }
+
+    /// Returns the `(lower, upper)` confidence bounds of a pulled arm.
+    fn confidence_bounds(&self, action_id: u32) -> (f64, f64) {
+        let count = self.counts[&action_id] as f64;
+        let num_arms = self.action_map.len() as f64;
+        let radius = ((4.0 * num_arms * count.powi(2) / self.delta).ln() / (2.0 * count)).sqrt();
+        let mean = self.get_average_reward(action_id);
+        (mean - radius, mean + radius)
+    }
+
+    /// Drops every active arm whose upper bound is below the best lower bound among active arms.
+    ///
+    /// An arm with NaN bounds (e.g. after a NaN reward) is never confidently worse, so it stays
+    /// active; this keeps `active_ids` non-empty even when every mean is NaN.
+    fn eliminate(&mut self) {
+        if self.active_ids.iter().any(|action_id| self.counts[action_id] == 0) {
+            return;
+        }
+        let best_lower = self
+            .active_ids
+            .iter()
+            .map(|&action_id| self.confidence_bounds(action_id).0)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let survivors: Vec<u32> = self
+            .active_ids
+            .iter()
+            .copied()
+            .filter(|&action_id| {
+                let upper = self.confidence_bounds(action_id).1;
+                upper.is_nan() || upper >= best_lower
+            })
+            .collect();
+        self.active_ids = survivors;
+    }
+}
+
+impl<A, R, C> Clone for SuccessiveEliminationPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    fn clone(&self) -> Self {
+        SuccessiveEliminationPolicy {
+            delta: self.delta,
+            counts: self.counts.clone(),
+            sum_rewards: self.sum_rewards.clone(),
+            active_ids: self.active_ids.clone(),
+            action_map: self.action_map.clone(),
+            step: Mutex::new(*self.step.lock().unwrap()),
+            frozen: self.frozen,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R, C> BanditPolicy<A, R, C> for SuccessiveEliminationPolicy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    /// Returns the next active arm in ID order, wrapping around after the last one.
+    /// Ignores context (non-contextual).
+    fn choose_action(&self, _context: &C) -> A {
+        let mut step = self.step.lock().unwrap();
+        let chosen_id = self.active_ids[(*step % self.active_ids.len() as u64) as usize];
+        *step += 1;
+        self.action_map.get(&chosen_id).unwrap().clone()
+    }
+
+    /// Records the reward, then eliminates arms that are confidently worse than another active arm.
+    /// Does nothing while learning is frozen.
+    fn update(&mut self, _context: &C, action: &A, reward: &R) {
+        if self.frozen {
+            return;
+        }
+        let action_id = action.id();
+        if !self.action_map.contains_key(&action_id) {
+            return;
+        }
+        *self.counts.get_mut(&action_id).unwrap() += 1;
+        *self.sum_rewards.get_mut(&action_id).unwrap() += reward.value();
+        self.eliminate();
+    }
+
+    fn set_frozen(&mut self, frozen: bool) {
+        self.frozen = frozen;
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Clears all statistics and reactivates every arm.
+    fn reset(&mut self) {
+        self.counts.values_mut().for_each(|count| *count = 0);
+        self.sum_rewards.values_mut().for_each(|sum| *sum = 0.0);
+        self.active_ids = self.action_map.sorted_ids();
+        *self.step.lock().unwrap() = 0;
+    }
+
+    /// Returns the average reward of the action (0.0 if never pulled).
+    fn expected_reward(&self, action: &A) -> Option<f64> {
+        self.action_map
+            .contains_key(&action.id())
+            .then(|| self.get_average_reward(action.id()))
+    }
+
+    fn action_ids(&self) -> Vec<u32> {
+        self.action_map.sorted_ids()
+    }
+
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        Ok(PolicyCheckpoint::SuccessiveElimination {
+            counts: self.counts.clone(),
+            sum_rewards: self.sum_rewards.clone(),
+            active_ids: self.active_ids.clone(),
+            step: *self.step.lock().unwrap(),
+        })
+    }
+
+    fn restore(&mut self, checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        let PolicyCheckpoint::SuccessiveElimination {
+            counts,
+            sum_rewards,
+            active_ids,
+            step,
+        } = checkpoint
+        else {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "expected a SuccessiveElimination checkpoint".to_string(),
+            });
+        };
+        if !self.action_map.has_same_ids(counts) || !self.action_map.has_same_ids(sum_rewards) {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "checkpoint action ids do not match the policy's actions".to_string(),
+            });
+        }
+        if active_ids.is_empty() || !active_ids.iter().all(|id| self.action_map.contains_key(id)) {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "active arms must be a non-empty subset of the policy's actions"
+                    .to_string(),
+            });
+        }
+
+        self.counts = counts.clone();
+        self.sum_rewards = sum_rewards.clone();
+        self.active_ids = active_ids.clone();
+        *self.step.lock().unwrap() = *step;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::entities::{DummyContext, NumericAction};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_successive_elimination_invalid_delta() {
+        let actions = vec![NumericAction::new(0i32, "a0")];
+        let err =
+            SuccessiveEliminationPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                1.0, &actions,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "delta".to_string(),
+                value: "1".to_string(),
+                expected_range: "0.0 to 1.0 exclusive".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_successive_elimination_keeps_only_best_arm() {
+        // Rewards are value / 10: 0.1, 0.5 and 0.9.
+        let actions = vec![
+            NumericAction::with_id(0, 1i32, "low"),
+            NumericAction::with_id(1, 5i32, "mid"),
+            NumericAction::with_id(2, 9i32, "high"),
+        ];
+        let mut policy =
+            SuccessiveEliminationPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                0.05, &actions,
+            )
+            .unwrap();
+        let ctx = DummyContext;
+
+        for _ in 0..3000 {
+            let action = policy.choose_action(&ctx);
+            policy.update(&ctx, &action, &DummyReward(action.value() as f64 / 10.0));
+        }
+
+        assert_eq!(policy.active_action_ids(), &[2]);
+        assert_eq!(policy.identified_best().map(|action| action.id()), Some(2));
+        for _ in 0..5 {
+            assert_eq!(policy.choose_action(&ctx).id(), 2);
+        }
+
+        policy.reset();
+        assert_eq!(policy.active_action_ids(), &[0, 1, 2]);
+    }
+
+    #[test]
+    fn test_successive_elimination_nan_rewards_keep_arms_active() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "a0"),
+            NumericAction::with_id(1, 1i32, "a1"),
+        ];
+        let mut policy =
+            SuccessiveEliminationPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                0.05, &actions,
+            )
+            .unwrap();
+        let ctx = DummyContext;
+
+        for _ in 0..10 {
+            let action = policy.choose_action(&ctx);
+            policy.update(&ctx, &action, &DummyReward(f64::NAN));
+        }
+        assert_eq!(policy.active_action_ids(), &[0, 1]);
+        policy.choose_action(&ctx);
+    }
+}
@@ -5,13 +5,17 @@ use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::Mutex;
 
-use crate::traits::entities::{Action, ActionStorage, Context, Reward};
-use crate::traits::policy::BanditPolicy;
+use crate::traits::entities::{Action, ActionStorage, Context, DummyContext, Reward};
+use crate::traits::policy::{BanditPolicy, Direction, PolicyCheckpoint};
 use crate::utils::error::OctopusError;
+use crate::utils::special::beta_quantile;
 
 /// Thompson Sampling policy for Multi-Armed Bandit problems.
+///
+/// Picks the arm with the highest Beta posterior sample, or the lowest under `Direction::Minimize`.
+/// Rewards are expected in `[0, 1]`; see `new_auto_scale` for rewards on an arbitrary scale.
 #[derive(Debug)]
-pub struct ThompsonSamplingPolicy<A, R, C>
+pub struct ThompsonSamplingPolicy<A, R, C = DummyContext>
 where
     C: Context,
     A: Action,
@@ -19,7 +23,14 @@ where
 {
     alpha_params: HashMap<u32, f64>,
     beta_params: HashMap<u32, f64>,
+    priors: HashMap<u32, (f64, f64)>,
     action_map: ActionStorage<A>,
+    direction: Direction,
+    auto_scale: bool,
+    observed_range: Option<(f64, f64)>,
+    max_param_cap: Option<f64>,
+    frozen: bool,
+    seed: u64,
     rng: Mutex<StdRng>,
     _phantom: PhantomData<(R, C)>,
 }
@@ -44,20 +55,202 @@ where
             initial_actions.iter().map(|action| (action.id(), 1.0)).collect();
         let beta_params: HashMap<u32, f64> =
             initial_actions.iter().map(|action| (action.id(), 1.0)).collect();
-
-        // Expand u64 seed to [u8; 32]
-        let mut seed_bytes = [0u8; 32];
-        seed_bytes[..8].copy_from_slice(&seed.to_le_bytes());
-        let rng = StdRng::from_seed(seed_bytes);
+        let priors: HashMap<u32, (f64, f64)> =
+            initial_actions.iter().map(|action| (action.id(), (1.0, 1.0))).collect();
 
         Ok(ThompsonSamplingPolicy {
             alpha_params,
             beta_params,
+            priors,
             action_map: ActionStorage::new(initial_actions)?,
-            rng: Mutex::new(rng),
+            direction: Direction::default(),
+            auto_scale: false,
+            observed_range: None,
+            max_param_cap: None,
+            frozen: false,
+            seed,
+            rng: Mutex::new(seeded_rng(seed)),
             _phantom: PhantomData,
         })
     }
+
+    /// Create new ThompsonSamplingPolicy with seeded RNG and per-arm Beta priors.
+    ///
+    /// * `priors` - `(alpha, beta)` prior for each action ID; unspecified actions fall back to `(1.0, 1.0)`.
+    ///
+    /// Returns an error if actions are empty, if a prior parameter is not strictly positive and finite,
+    /// or if a prior refers to an unknown action ID. `reset` restores these priors.
+    pub fn new_with_priors(
+        initial_actions: &[A],
+        priors: HashMap<u32, (f64, f64)>,
+        seed: u64,
+    ) -> Result<Self, OctopusError> {
+        let mut policy = Self::new(initial_actions, seed)?;
+        for (&action_id, &(alpha, beta)) in &priors {
+            if !policy.action_map.contains_key(&action_id) {
+                return Err(OctopusError::InvalidParameter {
+                    parameter_name: format!("priors[{}]", action_id),
+                    value: "unknown action id".to_string(),
+                    expected_range: "id of one of the initial actions".to_string(),
+                });
+            }
+            for (name, value) in [("alpha", alpha), ("beta", beta)] {
+                if !(value.is_finite() && value > 0.0) {
+                    return Err(OctopusError::InvalidParameter {
+                        parameter_name: format!("priors[{}].{}", action_id, name),
+                        value: value.to_string(),
+                        expected_range: "finite value greater than 0.0".to_string(),
+                    });
+                }
+            }
+            policy.priors.insert(action_id, (alpha, beta));
+        }
+        policy.reset();
+        Ok(policy)
+    }
+
+    /// Create new ThompsonSamplingPolicy with seeded RNG that normalizes rewards to `[0, 1]`.
+    ///
+    /// Each incoming reward is rescaled with the online minimum and maximum observed so far
+    /// (including the reward itself) before the alpha/beta update.
+    /// Cold start: until two distinct reward values have been seen, the range is degenerate
+    /// and rewards are mapped to the neutral value 0.5.
+    pub fn new_auto_scale(initial_actions: &[A], seed: u64) -> Result<Self, OctopusError> {
+        let mut policy = Self::new(initial_actions, seed)?;
+        policy.auto_scale = true;
+        Ok(policy)
+    }
+
+    /// Maps a raw reward into `[0, 1]` with the observed extrema, updating them first.
+    fn normalize_reward(&mut self, reward_value: f64) -> f64 {
+        let (min, max) = match self.observed_range {
+            Some((min, max)) => (min.min(reward_value), max.max(reward_value)),
+            None => (reward_value, reward_value),
+        };
+        self.observed_range = Some((min, max));
+        if max > min {
+            (reward_value - min) / (max - min)
+        } else {
+            0.5
+        }
+    }
+
+    /// Sets whether the sampled posterior value is maximized or minimized.
+    ///
+    /// Defaults to `Direction::Maximize`.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Caps each arm's total pseudo-count `alpha + beta`, so no posterior becomes so confident
+    /// that the arm is never explored again.
+    ///
+    /// When an update pushes `alpha + beta` above `cap`, both parameters are scaled down
+    /// proportionally to sum to `cap`, keeping the posterior mean but restoring its variance.
+    /// Returns an error if `cap` is not finite or not greater than 2.0 (the uniform prior's total).
+    pub fn with_max_param_cap(mut self, cap: f64) -> Result<Self, OctopusError> {
+        if !(cap.is_finite() && cap > 2.0) {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "max_param_cap".to_string(),
+                value: cap.to_string(),
+                expected_range: "finite value greater than 2.0".to_string(),
+            });
+        }
+        self.max_param_cap = Some(cap);
+        Ok(self)
+    }
+
+    /// Summarizes each arm's Beta posterior, in ascending ID order.
+    ///
+    /// Returns `(action_id, mean, variance, credible_interval)`, with `mean = alpha / (alpha + beta)`,
+    /// `variance = alpha * beta / ((alpha + beta)^2 * (alpha + beta + 1))` and the equal-tailed 95%
+    /// credible interval between the 2.5% and 97.5% quantiles of the posterior.
+    pub fn posterior_summary(&self) -> Vec<(u32, f64, f64, (f64, f64))> {
+        self.action_map
+            .sorted_ids()
+            .into_iter()
+            .map(|action_id| {
+                let alpha = self.alpha_params[&action_id];
+                let beta = self.beta_params[&action_id];
+                let total = alpha + beta;
+                let mean = alpha / total;
+                let variance = alpha * beta / (total * total * (total + 1.0));
+                let interval = (
+                    beta_quantile(0.025, alpha, beta),
+                    beta_quantile(0.975, alpha, beta),
+                );
+                (action_id, mean, variance, interval)
+            })
+            .collect()
+    }
+
+    /// Scales the arm's parameters down to sum to `max_param_cap` if they exceed it.
+    ///
+    /// Arms missing from either map (phantom IDs from a lenient `update`) start from 1.0 there.
+    fn apply_param_cap(&mut self, action_id: u32) {
+        let Some(cap) = self.max_param_cap else {
+            return;
+        };
+        let alpha = *self.alpha_params.entry(action_id).or_insert(1.0);
+        let beta = *self.beta_params.entry(action_id).or_insert(1.0);
+        if alpha + beta > cap {
+            let scale = cap / (alpha + beta);
+            self.alpha_params.insert(action_id, alpha * scale);
+            self.beta_params.insert(action_id, beta * scale);
+        }
+    }
+
+    /// Samples each candidate's Beta posterior and returns the candidate with the best sample,
+    /// along with that sample. `candidate_ids` must be non-empty and sorted.
+    ///
+    /// A lone candidate is returned with its posterior mean, without drawing from the RNG.
+    fn sample_best(&self, candidate_ids: &[u32]) -> (A, f64) {
+        if let [action_id] = candidate_ids {
+            let alpha = *self.alpha_params.get(action_id).unwrap_or(&1.0);
+            let beta = *self.beta_params.get(action_id).unwrap_or(&1.0);
+            return (
+                self.action_map.get(action_id).unwrap().clone(),
+                alpha / (alpha + beta),
+            );
+        }
+        let mut rng = self.rng.lock().unwrap();
+        let mut best_action_id = candidate_ids[0];
+        let mut best_sampled_reward = self.direction.worst();
+
+        for &action_id in candidate_ids {
+            let alpha = *self.alpha_params.get(&action_id).unwrap_or(&1.0);
+            let beta = *self.beta_params.get(&action_id).unwrap_or(&1.0);
+
+            if alpha <= 0.0 || beta <= 0.0 {
+                panic!(
+                    "Invalid Beta parameters: alpha = {}, beta = {}",
+                    alpha, beta
+                );
+            }
+
+            let beta_dist =
+                Beta::new(alpha, beta).expect("Beta distribution parameters must be positive.");
+            let sampled_reward = beta_dist.sample(&mut *rng);
+
+            if self.direction.prefers(sampled_reward, best_sampled_reward) {
+                best_sampled_reward = sampled_reward;
+                best_action_id = action_id;
+            }
+        }
+
+        (
+            self.action_map.get(&best_action_id).unwrap().clone(),
+            best_sampled_reward,
+        )
+    }
+}
+
+/// Builds an RNG from a u64 seed by expanding it to `[u8; 32]`.
+fn seeded_rng(seed: u64) -> StdRng {
+    let mut seed_bytes = [0u8; 32];
+    seed_bytes[..8].copy_from_slice(&seed.to_le_bytes());
+    StdRng::from_seed(seed_bytes)
 }
 
 impl<A, R, C> Clone for ThompsonSamplingPolicy<A, R, C>
@@ -68,15 +261,20 @@ where
 {
     fn clone(&self) -> Self {
         // Use a new seed or replicate seed as needed
-        let mut seed_bytes = [0u8; 32];
         let seed = rand::random::<u64>();
-        seed_bytes[..8].copy_from_slice(&seed.to_le_bytes());
 
         ThompsonSamplingPolicy {
             alpha_params: self.alpha_params.clone(),
             beta_params: self.beta_params.clone(),
+            priors: self.priors.clone(),
             action_map: self.action_map.clone(),
-            rng: Mutex::new(StdRng::from_seed(seed_bytes)),
+            direction: self.direction,
+            auto_scale: self.auto_scale,
+            observed_range: self.observed_range,
+            max_param_cap: self.max_param_cap,
+            frozen: self.frozen,
+            seed,
+            rng: Mutex::new(seeded_rng(seed)),
             _phantom: PhantomData,
         }
     }
@@ -89,55 +287,171 @@ where
     R: Reward,
     ThompsonSamplingPolicy<A, R, C>: Clone,
 {
-    fn choose_action(&self, _context: &C) -> A {
-        let mut rng = self.rng.lock().unwrap();
-        let mut best_action_id = *self.action_map.keys().next().unwrap();
-        let mut max_sampled_reward = -1.0;
-        
-        // sort action_ids to ensure deterministic output when the seeds are equal
-        let mut action_ids: Vec<_> = self.action_map.keys().copied().collect();
-        action_ids.sort_unstable();
-
-        for action_id in action_ids {
-            let alpha = *self.alpha_params.get(&action_id).unwrap_or(&1.0);
-            let beta = *self.beta_params.get(&action_id).unwrap_or(&1.0);
-
-            if alpha <= 0.0 || beta <= 0.0 {
-                panic!(
-                    "Invalid Beta parameters: alpha = {}, beta = {}",
-                    alpha, beta
-                );
-            }
-
-            let beta_dist =
-                Beta::new(alpha, beta).expect("Beta distribution parameters must be positive.");
-            let sampled_reward = beta_dist.sample(&mut *rng);
+    fn choose_action(&self, context: &C) -> A {
+        self.choose_action_scored(context).0
+    }
 
-            if sampled_reward > max_sampled_reward {
-                max_sampled_reward = sampled_reward;
-                best_action_id = action_id;
-            }
-        }
+    /// Samples from each arm's Beta posterior and returns the arm with the best sample,
+    /// using that sampled value as the score.
+    fn choose_action_scored(&self, _context: &C) -> (A, f64) {
+        // sort action_ids to ensure deterministic output when the seeds are equal
+        self.sample_best(&self.action_map.sorted_ids())
+    }
 
-        self.action_map.get(&best_action_id).unwrap().clone()
+    /// Samples only the posteriors of actions satisfying `predicate`.
+    fn choose_action_where(&self, _context: &C, predicate: impl Fn(&A) -> bool) -> Option<A> {
+        let candidate_ids: Vec<u32> = self
+            .action_map
+            .sorted_ids()
+            .into_iter()
+            .filter(|action_id| predicate(&self.action_map[action_id]))
+            .collect();
+        (!candidate_ids.is_empty()).then(|| self.sample_best(&candidate_ids).0)
     }
 
+    /// Updates the arm's posterior with the reward, unless learning is frozen.
     fn update(&mut self, _context: &C, action: &A, reward: &R) {
+        if self.frozen {
+            return;
+        }
         let action_id = action.id();
-        let reward_value = reward.value();
+        let reward_value = if self.auto_scale {
+            self.normalize_reward(reward.value())
+        } else {
+            reward.value()
+        };
 
         if reward_value >= 0.5 {
             *self.alpha_params.entry(action_id).or_insert(1.0) += 1.0;
         } else {
             *self.beta_params.entry(action_id).or_insert(1.0) += 1.0;
         }
+
+        self.apply_param_cap(action_id);
+    }
+
+    fn set_frozen(&mut self, frozen: bool) {
+        self.frozen = frozen;
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.frozen
     }
 
+    /// Adds the evidence gathered by `other` to each arm's posterior, i.e. its parameters minus
+    /// its own prior, so only this policy's prior is counted. Observed reward ranges are combined,
+    /// and `max_param_cap` is applied to the merged posteriors.
+    /// Does nothing while learning is frozen.
+    fn merge(&mut self, other: &Self) -> Result<(), OctopusError> {
+        if self.action_map.sorted_ids() != other.action_map.sorted_ids() {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "other".to_string(),
+                value: format!("{:?}", other.action_map.sorted_ids()),
+                expected_range: format!(
+                    "policy with action ids {:?}",
+                    self.action_map.sorted_ids()
+                ),
+            });
+        }
+        if self.frozen {
+            return Ok(());
+        }
+        for &action_id in other.action_map.keys() {
+            let (prior_alpha, prior_beta) = *other.priors.get(&action_id).unwrap_or(&(1.0, 1.0));
+            *self.alpha_params.get_mut(&action_id).unwrap() +=
+                other.alpha_params[&action_id] - prior_alpha;
+            *self.beta_params.get_mut(&action_id).unwrap() +=
+                other.beta_params[&action_id] - prior_beta;
+            self.apply_param_cap(action_id);
+        }
+        self.observed_range = match (self.observed_range, other.observed_range) {
+            (Some((min, max)), Some((other_min, other_max))) => {
+                Some((min.min(other_min), max.max(other_max)))
+            }
+            (range, other_range) => range.or(other_range),
+        };
+        Ok(())
+    }
+
+    /// Resets every arm's posterior to its prior. The RNG continues its stream; see `reset_rng`.
     fn reset(&mut self) {
         for &action_id in self.action_map.keys() {
-            *self.alpha_params.get_mut(&action_id).unwrap() = 1.0;
-            *self.beta_params.get_mut(&action_id).unwrap() = 1.0;
+            let (alpha, beta) = *self.priors.get(&action_id).unwrap_or(&(1.0, 1.0));
+            *self.alpha_params.get_mut(&action_id).unwrap() = alpha;
+            *self.beta_params.get_mut(&action_id).unwrap() = beta;
         }
+        self.observed_range = None;
+    }
+
+    fn reset_rng(&mut self) {
+        self.rng = Mutex::new(seeded_rng(self.seed));
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.reset_rng();
+    }
+
+    /// Returns a reset copy whose RNG restarts from this policy's seed.
+    fn fresh(&self) -> Self {
+        let mut policy = self.clone();
+        policy.seed = self.seed;
+        policy.reset();
+        policy.reset_rng();
+        policy
+    }
+
+    /// Returns the posterior mean `alpha / (alpha + beta)` of the action.
+    fn expected_reward(&self, action: &A) -> Option<f64> {
+        let alpha = *self.alpha_params.get(&action.id())?;
+        let beta = *self.beta_params.get(&action.id())?;
+        Some(alpha / (alpha + beta))
+    }
+
+    fn action_ids(&self) -> Vec<u32> {
+        self.action_map.sorted_ids()
+    }
+
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        Ok(PolicyCheckpoint::ThompsonSampling {
+            alpha_params: self.action_map.known_entries(&self.alpha_params),
+            beta_params: self.action_map.known_entries(&self.beta_params),
+            observed_range: self.observed_range,
+            seed: self.seed,
+        })
+    }
+
+    fn restore(&mut self, checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        let PolicyCheckpoint::ThompsonSampling {
+            alpha_params,
+            beta_params,
+            observed_range,
+            seed,
+        } = checkpoint
+        else {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "expected a ThompsonSampling checkpoint".to_string(),
+            });
+        };
+        if !self.action_map.has_same_ids(alpha_params) || !self.action_map.has_same_ids(beta_params)
+        {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "checkpoint action ids do not match the policy's actions".to_string(),
+            });
+        }
+        let is_valid_param = |param: &f64| param.is_finite() && *param > 0.0;
+        if !alpha_params.values().all(is_valid_param) || !beta_params.values().all(is_valid_param) {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "Beta parameters must be finite and greater than 0".to_string(),
+            });
+        }
+
+        self.alpha_params = alpha_params.clone();
+        self.beta_params = beta_params.clone();
+        self.observed_range = *observed_range;
+        self.seed = *seed;
+        self.rng = Mutex::new(seeded_rng(*seed));
+        Ok(())
     }
 }
 
@@ -146,6 +460,7 @@ mod tests {
     use super::*;
     use crate::traits::entities::{DummyContext, NumericAction};
     use crate::utils::error::OctopusError;
+    use rand::Rng;
 
     #[derive(Debug, Clone, PartialEq)]
     struct DummyReward(f64);
@@ -212,7 +527,7 @@ mod tests {
             NumericAction::new(10i32, "A"),
             NumericAction::new(20i32, "B"),
         ];
-        let id0 = actions.get(0).unwrap().id();
+        let id0 = actions.first().unwrap().id();
 
         let mut policy =
             ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
@@ -221,7 +536,7 @@ mod tests {
             .unwrap();
         let ctx = DummyContext;
 
-        let a = actions.get(0).unwrap();
+        let a = actions.first().unwrap();
 
         // Simulate a reward of 1.0 (success)
         policy.update(&ctx, a, &DummyReward(1.0));
@@ -241,7 +556,7 @@ mod tests {
             NumericAction::new(20i32, "B"),
         ];
 
-        let id0 = actions.get(0).unwrap().id();
+        let id0 = actions.first().unwrap().id();
 
         let mut policy =
             ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
@@ -249,17 +564,17 @@ mod tests {
             )
             .unwrap();
         let ctx = DummyContext;
-        let a = actions.get(0).unwrap();
+        let a = actions.first().unwrap();
 
-        policy.update(&ctx, &a, &DummyReward(1.0));
-        policy.update(&ctx, &a, &DummyReward(0.0));
+        policy.update(&ctx, a, &DummyReward(1.0));
+        policy.update(&ctx, a, &DummyReward(0.0));
         assert_ne!(*policy.alpha_params.get(&id0).unwrap(), 1.0);
         assert_ne!(*policy.beta_params.get(&id0).unwrap(), 1.0);
 
         policy.reset();
         for id in policy.action_map.keys() {
-            assert_eq!(*policy.alpha_params.get(&id).unwrap(), 1.0);
-            assert_eq!(*policy.beta_params.get(&id).unwrap(), 1.0);
+            assert_eq!(*policy.alpha_params.get(id).unwrap(), 1.0);
+            assert_eq!(*policy.beta_params.get(id).unwrap(), 1.0);
         }
     }
 
@@ -271,19 +586,572 @@ mod tests {
         ];
 
         let ctx = DummyContext;
-        
-        for seed in (1000..1500) {
-            let policy1 = ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
-                &actions, seed,
-            ).unwrap();
-            let policy2 = ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
-                &actions, seed,
-            ).unwrap();
+
+        for seed in 1000..1500 {
+            let policy1 =
+                ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                    &actions, seed,
+                )
+                .unwrap();
+            let policy2 =
+                ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                    &actions, seed,
+                )
+                .unwrap();
 
             let chosen1 = policy1.choose_action(&ctx);
             let chosen2 = policy2.choose_action(&ctx);
 
-            assert_eq!(chosen1, chosen2, "Same seed should produce same result: {:?}", seed);
+            assert_eq!(
+                chosen1, chosen2,
+                "Same seed should produce same result: {:?}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_thompson_scored_returns_sample_in_unit_interval() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "A"),
+            NumericAction::with_id(1, 20i32, "B"),
+        ];
+        let policy = ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            &actions, 7,
+        )
+        .unwrap();
+        let twin = ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            &actions, 7,
+        )
+        .unwrap();
+
+        let (chosen, score) = policy.choose_action_scored(&DummyContext);
+        assert!((0.0..=1.0).contains(&score));
+        // The scored variant draws the same samples as choose_action.
+        assert_eq!(chosen, twin.choose_action(&DummyContext));
+    }
+
+    #[test]
+    fn test_thompson_checkpoint_round_trip() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "A"),
+            NumericAction::with_id(1, 20i32, "B"),
+        ];
+        let mut policy =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 99,
+            )
+            .unwrap();
+        let ctx = DummyContext;
+        policy.update(&ctx, &actions[0], &DummyReward(1.0));
+        policy.update(&ctx, &actions[1], &DummyReward(0.0));
+        policy.update(&ctx, &actions[1], &DummyReward(1.0));
+
+        let checkpoint = policy.checkpoint().unwrap();
+        let mut restored =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 1,
+            )
+            .unwrap();
+        restored.restore(&checkpoint).unwrap();
+        assert_eq!(restored.checkpoint().unwrap(), checkpoint);
+        assert_eq!(*restored.alpha_params.get(&0).unwrap(), 2.0);
+        assert_eq!(*restored.beta_params.get(&1).unwrap(), 2.0);
+
+        // Both policies restored from the same checkpoint share the same RNG stream.
+        let mut twin =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 2,
+            )
+            .unwrap();
+        twin.restore(&checkpoint).unwrap();
+        for _ in 0..20 {
+            assert_eq!(restored.choose_action(&ctx), twin.choose_action(&ctx));
+        }
+    }
+
+    #[test]
+    fn test_thompson_restore_rejects_wrong_policy_kind() {
+        let actions = vec![NumericAction::with_id(0, 10i32, "A")];
+        let mut policy =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 3,
+            )
+            .unwrap();
+        let checkpoint = PolicyCheckpoint::EpsilonGreedy {
+            counts: HashMap::from([(0, 1)]),
+            sum_rewards: HashMap::from([(0, 1.0)]),
+            total_pulls: 1,
+            explore_count: 0,
+            exploit_count: 1,
+            seed: 0,
+        };
+        assert_eq!(
+            policy.restore(&checkpoint).unwrap_err(),
+            OctopusError::InvalidCheckpoint {
+                reason: "expected a ThompsonSampling checkpoint".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_thompson_restore_rejects_invalid_beta_params() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "A"),
+            NumericAction::with_id(1, 20i32, "B"),
+        ];
+        let mut policy =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 3,
+            )
+            .unwrap();
+        for bad_param in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let checkpoint = PolicyCheckpoint::ThompsonSampling {
+                alpha_params: HashMap::from([(0, 1.0), (1, 1.0)]),
+                beta_params: HashMap::from([(0, 1.0), (1, bad_param)]),
+                observed_range: None,
+                seed: 0,
+            };
+            assert_eq!(
+                policy.restore(&checkpoint).unwrap_err(),
+                OctopusError::InvalidCheckpoint {
+                    reason: "Beta parameters must be finite and greater than 0".to_string(),
+                }
+            );
+        }
+        // The rejected checkpoints left the policy usable.
+        policy.choose_action(&DummyContext);
+        assert_eq!(*policy.beta_params.get(&1).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_thompson_action_ids_and_count() {
+        let actions = vec![
+            NumericAction::with_id(3, 10i32, "A"),
+            NumericAction::with_id(1, 20i32, "B"),
+        ];
+        let policy = ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            &actions, 42,
+        )
+        .unwrap();
+        assert_eq!(policy.n_actions(), 2);
+        assert_eq!(policy.action_ids(), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_thompson_auto_scale_learns_better_arm() {
+        use rand::Rng;
+
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "worse"),
+            NumericAction::with_id(1, 20i32, "better"),
+        ];
+        let mut policy =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new_auto_scale(
+                &actions, 11,
+            )
+            .unwrap();
+        let ctx = DummyContext;
+        let mut reward_rng = StdRng::seed_from_u64(5);
+
+        // Cold start: a single observation maps to the neutral value.
+        assert_eq!(policy.normalize_reward(50.0), 0.5);
+        policy.reset();
+        assert_eq!(policy.observed_range, None);
+
+        for _ in 0..500 {
+            let action = policy.choose_action(&ctx);
+            // Rewards live in [0, 100]: the better arm pays in [60, 100], the worse in [0, 40].
+            let reward = if action.id() == 1 {
+                reward_rng.random_range(60.0..100.0)
+            } else {
+                reward_rng.random_range(0.0..40.0)
+            };
+            policy.update(&ctx, &action, &DummyReward(reward));
+        }
+
+        let better_picks = (0..200).filter(|_| policy.choose_action(&ctx).id() == 1).count();
+        assert!(
+            better_picks > 180,
+            "better arm picked {} / 200 times",
+            better_picks
+        );
+    }
+
+    #[test]
+    fn test_thompson_fresh_is_deterministic() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "A"),
+            NumericAction::with_id(1, 20i32, "B"),
+        ];
+        let mut policy =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 21,
+            )
+            .unwrap();
+        let ctx = DummyContext;
+        policy.update(&ctx, &actions[0], &DummyReward(1.0));
+
+        let fresh = policy.fresh();
+        let untouched =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 21,
+            )
+            .unwrap();
+        assert_eq!(fresh.checkpoint().unwrap(), untouched.checkpoint().unwrap());
+        for _ in 0..20 {
+            assert_eq!(fresh.choose_action(&ctx), untouched.choose_action(&ctx));
+        }
+    }
+
+    #[test]
+    fn test_thompson_priors_validation() {
+        let actions = vec![NumericAction::with_id(0, 10i32, "A")];
+        let err = ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new_with_priors(
+            &actions,
+            HashMap::from([(0, (2.0, 0.0))]),
+            42,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "priors[0].beta".to_string(),
+                value: "0".to_string(),
+                expected_range: "finite value greater than 0.0".to_string(),
+            }
+        );
+
+        let err = ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new_with_priors(
+            &actions,
+            HashMap::from([(9, (2.0, 2.0))]),
+            42,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "priors[9]".to_string(),
+                value: "unknown action id".to_string(),
+                expected_range: "id of one of the initial actions".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_thompson_optimistic_prior_is_favoured_early() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "plain"),
+            NumericAction::with_id(1, 20i32, "optimistic"),
+        ];
+        let mut policy =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new_with_priors(
+                &actions,
+                HashMap::from([(1, (20.0, 1.0))]),
+                42,
+            )
+            .unwrap();
+        let ctx = DummyContext;
+
+        assert_eq!(*policy.alpha_params.get(&0).unwrap(), 1.0);
+        assert_eq!(*policy.alpha_params.get(&1).unwrap(), 20.0);
+
+        let optimistic_picks = (0..100).filter(|_| policy.choose_action(&ctx).id() == 1).count();
+        assert!(
+            optimistic_picks > 80,
+            "optimistic arm picked {} / 100",
+            optimistic_picks
+        );
+
+        policy.update(&ctx, &actions[1], &DummyReward(0.0));
+        policy.reset();
+        assert_eq!(*policy.alpha_params.get(&1).unwrap(), 20.0);
+        assert_eq!(*policy.beta_params.get(&1).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_thompson_reset_keeps_stream_and_reset_rng_restarts_it() {
+        let actions: Vec<NumericAction<i32>> =
+            (0..5).map(|id| NumericAction::with_id(id, id as i32, "arm")).collect();
+        let dummy_context = DummyContext;
+        let mut policy =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 7,
+            )
+            .unwrap();
+        let reference = policy.fresh();
+        let draw = |policy: &ThompsonSamplingPolicy<
+            NumericAction<i32>,
+            DummyReward,
+            DummyContext,
+        >|
+         -> Vec<u32> {
+            (0..20).map(|_| policy.choose_action(&dummy_context).id()).collect()
+        };
+        let reference_stream: Vec<u32> =
+            draw(&reference).into_iter().chain(draw(&reference)).collect();
+
+        let first = draw(&policy);
+        policy.update(&dummy_context, &actions[0], &DummyReward(1.0));
+        policy.reset();
+        let after_reset = draw(&policy);
+        assert_eq!([first.clone(), after_reset].concat(), reference_stream);
+
+        policy.reset_rng();
+        assert_eq!(draw(&policy), first);
+    }
+
+    #[test]
+    fn test_thompson_max_param_cap_keeps_exploring_dominated_arm() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "strong"),
+            NumericAction::with_id(1, 1i32, "weak"),
+        ];
+        let dummy_context = DummyContext;
+        let train =
+            |policy: &mut ThompsonSamplingPolicy<NumericAction<i32>, DummyReward, DummyContext>| {
+                // 60% vs 40% success rate, fed deterministically.
+                for round in 0..2_000 {
+                    for (action, successes) in actions.iter().zip([3, 2]) {
+                        let reward = if round % 5 < successes { 1.0 } else { 0.0 };
+                        policy.update(&dummy_context, action, &DummyReward(reward));
+                    }
+                }
+            };
+        let weak_picks =
+            |policy: &ThompsonSamplingPolicy<NumericAction<i32>, DummyReward, DummyContext>| {
+                (0..1000).filter(|_| policy.choose_action(&dummy_context).id() == 1).count()
+            };
+
+        let mut uncapped =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 42,
+            )
+            .unwrap();
+        train(&mut uncapped);
+        assert_eq!(weak_picks(&uncapped), 0);
+
+        let mut capped =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 42,
+            )
+            .unwrap()
+            .with_max_param_cap(20.0)
+            .unwrap();
+        train(&mut capped);
+        let (alpha, beta) = (capped.alpha_params[&1], capped.beta_params[&1]);
+        assert!((alpha + beta - 20.0).abs() < 1e-9);
+        assert!((alpha / (alpha + beta) - 0.4).abs() < 0.05);
+        assert!(weak_picks(&capped) > 20, "weak arm starved under the cap");
+
+        let err = ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            &actions, 42,
+        )
+        .unwrap()
+        .with_max_param_cap(1.5)
+        .unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "max_param_cap".to_string(),
+                value: "1.5".to_string(),
+                expected_range: "finite value greater than 2.0".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_thompson_expected_reward_is_posterior_mean() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "A"),
+            NumericAction::with_id(1, 20i32, "B"),
+        ];
+        let dummy_context = DummyContext;
+        let mut policy =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 42,
+            )
+            .unwrap();
+        for reward in [1.0, 1.0, 0.0, 1.0] {
+            policy.update(&dummy_context, &actions[0], &DummyReward(reward));
+        }
+
+        // Beta(1 + 3, 1 + 1) for A, the uniform prior for B.
+        assert_eq!(policy.expected_reward(&actions[0]), Some(4.0 / 6.0));
+        assert_eq!(policy.expected_reward(&actions[1]), Some(0.5));
+        assert_eq!(
+            policy.expected_reward(&NumericAction::with_id(9, 0i32, "Unknown")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_thompson_single_arm_skips_rng() {
+        let actions = vec![NumericAction::with_id(7, 1i32, "only")];
+        let policy = ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            &actions, 3,
+        )
+        .unwrap();
+        let dummy_context = DummyContext;
+
+        for _ in 0..10 {
+            assert_eq!(policy.choose_action(&dummy_context).id(), 7);
+            let (action, score) = policy.choose_action_scored(&dummy_context);
+            assert_eq!((action.id(), score), (7, 0.5));
+            assert_eq!(
+                policy.choose_action_where(&dummy_context, |_| true).unwrap().id(),
+                7
+            );
+        }
+        let next_draw: u64 = policy.rng.lock().unwrap().random();
+        assert_eq!(next_draw, seeded_rng(3).random::<u64>());
+    }
+
+    #[test]
+    fn test_thompson_merge_matches_training_on_concatenated_data() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "A"),
+            NumericAction::with_id(1, 20i32, "B"),
+        ];
+        let priors = HashMap::from([(0, (2.0, 3.0))]);
+        let shard_a = [(0, 1.0), (1, 0.0), (0, 1.0)];
+        let shard_b = [(1, 1.0), (0, 0.0), (1, 1.0), (1, 0.0)];
+        let dummy_context = DummyContext;
+        let train = |data: &[(usize, f64)]| {
+            let mut policy = ThompsonSamplingPolicy::<
+                NumericAction<i32>,
+                DummyReward,
+                DummyContext,
+            >::new_with_priors(&actions, priors.clone(), 42)
+            .unwrap();
+            for &(index, reward) in data {
+                policy.update(&dummy_context, &actions[index], &DummyReward(reward));
+            }
+            policy
+        };
+
+        let mut merged = train(&shard_a);
+        merged.merge(&train(&shard_b)).unwrap();
+        let combined = train(&[shard_a.as_slice(), shard_b.as_slice()].concat());
+        assert_eq!(merged.checkpoint().unwrap(), combined.checkpoint().unwrap());
+    }
+
+    #[test]
+    fn test_thompson_merge_uses_other_priors_and_cap() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "A"),
+            NumericAction::with_id(1, 20i32, "B"),
+        ];
+        let dummy_context = DummyContext;
+        let mut policy =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 42,
+            )
+            .unwrap();
+        let mut other = ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new_with_priors(
+            &actions,
+            HashMap::from([(0, (5.0, 5.0))]),
+            7,
+        )
+        .unwrap();
+        other.update(&dummy_context, &actions[0], &DummyReward(1.0));
+
+        // Only the other policy's single success is added, not the difference between the priors.
+        policy.merge(&other).unwrap();
+        assert_eq!(policy.alpha_params[&0], 2.0);
+        assert_eq!(policy.beta_params[&0], 1.0);
+
+        let mut capped = policy.fresh().with_max_param_cap(4.0).unwrap();
+        for _ in 0..10 {
+            other.update(&dummy_context, &actions[0], &DummyReward(1.0));
+        }
+        capped.merge(&other).unwrap();
+        let (alpha, beta) = (capped.alpha_params[&0], capped.beta_params[&0]);
+        assert!((alpha + beta - 4.0).abs() < 1e-9, "{} + {}", alpha, beta);
+    }
+
+    #[test]
+    fn test_thompson_capped_update_of_unknown_action_does_not_panic() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "A"),
+            NumericAction::with_id(1, 20i32, "B"),
+        ];
+        let dummy_context = DummyContext;
+        let mut policy =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 42,
+            )
+            .unwrap()
+            .with_max_param_cap(3.0)
+            .unwrap();
+        let unknown = NumericAction::with_id(9, 0i32, "Unknown");
+
+        for reward in [1.0, 1.0, 0.0] {
+            policy.update(&dummy_context, &unknown, &DummyReward(reward));
+        }
+        let (alpha, beta) = (policy.alpha_params[&9], policy.beta_params[&9]);
+        assert!((alpha + beta - 3.0).abs() < 1e-9, "{} + {}", alpha, beta);
+    }
+
+    #[test]
+    fn test_thompson_frozen_ignores_updates() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "A"),
+            NumericAction::with_id(1, 20i32, "B"),
+        ];
+        let mut policy =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 42,
+            )
+            .unwrap();
+        let dummy_context = DummyContext;
+        policy.update(&dummy_context, &actions[0], &DummyReward(1.0));
+        let trained = policy.checkpoint().unwrap();
+
+        policy.set_frozen(true);
+        assert!(policy.is_frozen());
+        for _ in 0..5 {
+            policy.update(&dummy_context, &actions[1], &DummyReward(1.0));
+        }
+        assert_eq!(policy.checkpoint().unwrap(), trained);
+        assert!(policy.clone().is_frozen());
+
+        policy.set_frozen(false);
+        policy.update(&dummy_context, &actions[1], &DummyReward(1.0));
+        assert_eq!(policy.alpha_params[&1], 2.0);
+    }
+
+    #[test]
+    fn test_thompson_posterior_summary_moves_toward_one() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "A"),
+            NumericAction::with_id(1, 20i32, "B"),
+        ];
+        let mut policy =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 42,
+            )
+            .unwrap();
+        let dummy_context = DummyContext;
+
+        let before = policy.posterior_summary();
+        // Beta(1, 1): mean 0.5, variance 1 / 12, interval [0.025, 0.975].
+        assert_eq!(before[0].0, 0);
+        assert_eq!(before[0].1, 0.5);
+        assert!((before[0].2 - 1.0 / 12.0).abs() < 1e-12);
+        assert!((before[0].3.0 - 0.025).abs() < 1e-9 && (before[0].3.1 - 0.975).abs() < 1e-9);
+
+        for _ in 0..8 {
+            policy.update(&dummy_context, &actions[0], &DummyReward(1.0));
         }
+        let after = policy.posterior_summary();
+        let (action_id, mean, variance, (lower, upper)) = after[0];
+        assert_eq!(action_id, 0);
+        assert_eq!(mean, 0.9);
+        assert!(variance < before[0].2);
+        assert!(lower > before[0].3.0 && upper > before[0].3.1);
+        assert!(lower < mean && mean < upper && upper <= 1.0);
+        // The untouched arm keeps its prior.
+        assert_eq!(after[1], before[1]);
     }
 }
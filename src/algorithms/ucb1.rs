@@ -0,0 +1,379 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use crate::traits::entities::{Action, ActionStorage, Context, DummyContext, Reward};
+use crate::traits::policy::{BanditPolicy, PolicyCheckpoint};
+use crate::utils::error::OctopusError;
+
+/// Scaling of the UCB1 exploration bonus.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UcbBonus {
+    /// Uses the constant `c` as given.
+    Fixed(f64),
+    /// Uses `c / sqrt(K)` for `K` actions, softening the early exploration phase with many arms.
+    Auto(f64),
+}
+
+impl UcbBonus {
+    /// Returns the exploration constant to use with `num_actions` actions.
+    fn constant(&self, num_actions: usize) -> f64 {
+        match *self {
+            UcbBonus::Fixed(c) => c,
+            UcbBonus::Auto(c) => c / (num_actions as f64).sqrt(),
+        }
+    }
+}
+
+/// Returns the UCB score `mean + exploration * sqrt(ln(total_pulls) / count)` of an arm with
+/// `count` pulls and reward sum `sum_rewards`, or infinity if it has never been pulled.
+///
+/// Shared by `Ucb1Policy` and `SoftUCBPolicy`.
+pub(crate) fn ucb_score(count: u64, sum_rewards: f64, total_pulls: u64, exploration: f64) -> f64 {
+    if count == 0 {
+        return f64::INFINITY;
+    }
+    let mean = sum_rewards / count as f64;
+    let bonus = ((total_pulls as f64).ln() / count as f64).sqrt();
+    mean + exploration * bonus
+}
+
+/// UCB1 policy for Multi-Armed Bandit problems.
+///
+/// Picks the action with the highest score `mean_i + c * sqrt(ln(t) / n_i)`, where the constant `c`
+/// is configured by `UcbBonus`. Actions that have never been pulled are selected first, and ties
+/// are resolved in favour of the smallest ID, so the policy is fully deterministic.
+#[derive(Debug)]
+pub struct Ucb1Policy<A, R, C = DummyContext>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    bonus: UcbBonus,
+    counts: HashMap<u32, u64>,
+    sum_rewards: HashMap<u32, f64>,
+    action_map: ActionStorage<A>,
+    total_pulls: u64,
+    frozen: bool,
+    _phantom: PhantomData<(R, C)>,
+}
+
+impl<A, R, C> Ucb1Policy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    /// Creates a new Ucb1Policy.
+    ///
+    /// * `initial_actions` - Slice of all possible actions.
+    /// * `bonus` - Exploration constant and its scaling; `UcbBonus::Fixed(2f64.sqrt())` is classic UCB1.
+    ///
+    /// Returns an error if actions are empty or if the constant is negative or not finite.
+    pub fn new(initial_actions: &[A], bonus: UcbBonus) -> Result<Self, OctopusError> {
+        if initial_actions.is_empty() {
+            return Err(OctopusError::EmptyCollection {
+                collection_name: "initial_actions".to_string(),
+            });
+        }
+        let (UcbBonus::Fixed(c) | UcbBonus::Auto(c)) = bonus;
+        if !(c.is_finite() && c >= 0.0) {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "bonus".to_string(),
+                value: c.to_string(),
+                expected_range: "finite value of 0.0 or greater".to_string(),
+            });
+        }
+
+        Ok(Ucb1Policy {
+            bonus,
+            counts: initial_actions.iter().map(|action| (action.id(), 0)).collect(),
+            sum_rewards: initial_actions.iter().map(|action| (action.id(), 0.0)).collect(),
+            action_map: ActionStorage::new(initial_actions)?,
+            total_pulls: 0,
+            frozen: false,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the exploration constant `c` in effect for the current action set.
+    pub fn exploration_constant(&self) -> f64 {
+        self.bonus.constant(self.action_map.len())
+    }
+
+    /// Returns the UCB score of the given action ID, or infinity if it has never been pulled.
+    fn ucb_score(&self, action_id: u32) -> f64 {
+        ucb_score(
+            *self.counts.get(&action_id).unwrap_or(&0),
+            *self.sum_rewards.get(&action_id).unwrap_or(&0.0),
+            self.total_pulls,
+            self.exploration_constant(),
+        )
+    }
+}
+
+impl<A, R, C> Clone for Ucb1Policy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    fn clone(&self) -> Self {
+        Ucb1Policy {
+            bonus: self.bonus,
+            counts: self.counts.clone(),
+            sum_rewards: self.sum_rewards.clone(),
+            action_map: self.action_map.clone(),
+            total_pulls: self.total_pulls,
+            frozen: self.frozen,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R, C> BanditPolicy<A, R, C> for Ucb1Policy<A, R, C>
+where
+    C: Context,
+    A: Action,
+    R: Reward,
+{
+    fn choose_action(&self, context: &C) -> A {
+        self.choose_action_scored(context).0
+    }
+
+    /// Returns the action with the highest UCB score, along with that score.
+    /// Ignores context (non-contextual).
+    fn choose_action_scored(&self, _context: &C) -> (A, f64) {
+        let action_ids = self.action_map.sorted_ids();
+        let mut best_action_id = action_ids[0];
+        let mut best_score = self.ucb_score(best_action_id);
+        for action_id in action_ids {
+            let score = self.ucb_score(action_id);
+            if score > best_score {
+                best_score = score;
+                best_action_id = action_id;
+            }
+        }
+        (
+            self.action_map.get(&best_action_id).unwrap().clone(),
+            best_score,
+        )
+    }
+
+    /// Records the reward. Non-finite rewards are ignored, as they would make every later score NaN.
+    fn update(&mut self, _context: &C, action: &A, reward: &R) {
+        if self.frozen || !reward.value().is_finite() {
+            return;
+        }
+        let action_id = action.id();
+        *self.counts.entry(action_id).or_insert(0) += 1;
+        *self.sum_rewards.entry(action_id).or_insert(0.0) += reward.value();
+        self.total_pulls += 1;
+    }
+
+    fn set_frozen(&mut self, frozen: bool) {
+        self.frozen = frozen;
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    fn reset(&mut self) {
+        self.total_pulls = 0;
+        for &action_id in self.action_map.keys() {
+            *self.counts.get_mut(&action_id).unwrap() = 0;
+            *self.sum_rewards.get_mut(&action_id).unwrap() = 0.0;
+        }
+    }
+
+    /// Returns the average reward of the action (0.0 if never pulled), without the UCB bonus.
+    fn expected_reward(&self, action: &A) -> Option<f64> {
+        let count = *self.counts.get(&action.id())?;
+        let sum_reward = *self.sum_rewards.get(&action.id())?;
+        Some(if count == 0 {
+            0.0
+        } else {
+            sum_reward / count as f64
+        })
+    }
+
+    fn action_ids(&self) -> Vec<u32> {
+        self.action_map.sorted_ids()
+    }
+
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        Ok(PolicyCheckpoint::Ucb1 {
+            counts: self.action_map.known_entries(&self.counts),
+            sum_rewards: self.action_map.known_entries(&self.sum_rewards),
+            total_pulls: self.total_pulls,
+        })
+    }
+
+    fn restore(&mut self, checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        let PolicyCheckpoint::Ucb1 {
+            counts,
+            sum_rewards,
+            total_pulls,
+        } = checkpoint
+        else {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "expected a Ucb1 checkpoint".to_string(),
+            });
+        };
+        if !self.action_map.has_same_ids(counts) || !self.action_map.has_same_ids(sum_rewards) {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "checkpoint action ids do not match the policy's actions".to_string(),
+            });
+        }
+
+        self.counts = counts.clone();
+        self.sum_rewards = sum_rewards.clone();
+        self.total_pulls = *total_pulls;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environments::gaussian::GaussianEnvironment;
+    use crate::simulation::simulator::Simulator;
+    use crate::traits::entities::{DummyContext, NumericAction};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl From<f64> for DummyReward {
+        fn from(value: f64) -> Self {
+            DummyReward(value)
+        }
+    }
+
+    #[test]
+    fn test_ucb1_pulls_each_arm_then_exploits() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+            NumericAction::with_id(2, 2i32, "C"),
+        ];
+        let mut policy = Ucb1Policy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            &actions,
+            UcbBonus::Fixed(0.1),
+        )
+        .unwrap();
+        let ctx = DummyContext;
+
+        let mut first_pulls = Vec::new();
+        for _ in 0..3 {
+            let action = policy.choose_action(&ctx);
+            first_pulls.push(action.id());
+            policy.update(
+                &ctx,
+                &action,
+                &DummyReward([0.2, 0.8, 0.5][action.id() as usize]),
+            );
+        }
+        assert_eq!(first_pulls, vec![0, 1, 2]);
+        assert_eq!(policy.choose_action(&ctx).id(), 1);
+    }
+
+    #[test]
+    fn test_ucb1_invalid_bonus() {
+        let actions = vec![NumericAction::with_id(0, 0i32, "A")];
+        let err = Ucb1Policy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            &actions,
+            UcbBonus::Auto(-1.0),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "bonus".to_string(),
+                value: "-1".to_string(),
+                expected_range: "finite value of 0.0 or greater".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_ucb1_auto_bonus_lowers_regret_with_many_arms() {
+        let num_arms = 100;
+        let actions: Vec<NumericAction<i32>> =
+            (0..num_arms).map(|id| NumericAction::with_id(id, id as i32, "arm")).collect();
+        let arms: HashMap<u32, (f64, f64)> =
+            (0..num_arms).map(|id| (id, (id as f64 / num_arms as f64, 0.1))).collect();
+        let environment =
+            GaussianEnvironment::<NumericAction<i32>, DummyReward>::new(arms, 42).unwrap();
+
+        let regret_with = |bonus: UcbBonus| {
+            let policy = Ucb1Policy::new(&actions, bonus).unwrap();
+            let results = Simulator::new(policy, environment.clone()).run_episode(3000, &actions);
+            *results.steps_regret.last().unwrap()
+        };
+        let fixed = regret_with(UcbBonus::Fixed(2f64.sqrt()));
+        let auto = regret_with(UcbBonus::Auto(2f64.sqrt()));
+
+        assert!(
+            auto < fixed,
+            "auto-scaled regret {} vs fixed regret {}",
+            auto,
+            fixed
+        );
+    }
+
+    #[test]
+    fn test_ucb1_frozen_ignores_updates() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+        ];
+        let mut policy = Ucb1Policy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            &actions,
+            UcbBonus::Fixed(0.1),
+        )
+        .unwrap();
+        let ctx = DummyContext;
+        policy.update(&ctx, &actions[0], &DummyReward(0.5));
+        let trained = policy.checkpoint().unwrap();
+
+        policy.set_frozen(true);
+        assert!(policy.is_frozen());
+        policy.update(&ctx, &actions[1], &DummyReward(1.0));
+        assert_eq!(policy.checkpoint().unwrap(), trained);
+        assert!(policy.clone().is_frozen());
+
+        policy.set_frozen(false);
+        policy.update(&ctx, &actions[1], &DummyReward(1.0));
+        assert_eq!(policy.counts[&1], 1);
+    }
+
+    #[test]
+    fn test_ucb1_ignores_non_finite_rewards() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+        ];
+        let mut policy = Ucb1Policy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            &actions,
+            UcbBonus::Fixed(0.1),
+        )
+        .unwrap();
+        let ctx = DummyContext;
+        policy.update(&ctx, &actions[0], &DummyReward(0.5));
+        policy.update(&ctx, &actions[1], &DummyReward(0.2));
+        let before = policy.checkpoint().unwrap();
+
+        for reward in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            policy.update(&ctx, &actions[1], &DummyReward(reward));
+        }
+        assert_eq!(policy.checkpoint().unwrap(), before);
+        assert_eq!(policy.choose_action(&ctx).id(), 0);
+    }
+}
@@ -0,0 +1,200 @@
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+use crate::traits::entities::{Action, DummyContext, Reward};
+use crate::traits::environment::Environment;
+
+/// Progress of an adversarial episode.
+#[derive(Debug, Clone, Copy, Default)]
+struct AdversaryState {
+    /// Number of contexts served so far; the current step is `steps_started - 1`.
+    steps_started: usize,
+    /// Action chosen in the previous step, as seen by the adversary.
+    previous_action_id: Option<u32>,
+    /// Action chosen in the current step, if any.
+    current_action_id: Option<u32>,
+}
+
+/// Adversarial reward environment for worst-case testing.
+///
+/// Every reward is chosen by a closure `Fn(action_id, step, previous_action_id) -> f64`.
+/// Oblivious adversaries depend only on the action and the step; adaptive adversaries can also
+/// react to the action the policy chose in the previous step (`None` on the first step).
+///
+/// A step starts with each call to `get_context`, so the environment assumes the simulator's
+/// one-context-per-step loop. The environment is non-contextual and always serves a `DummyContext`.
+pub struct AdversarialEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    adversary: Arc<dyn Fn(u32, usize, Option<u32>) -> f64 + Send + Sync>,
+    state: Mutex<AdversaryState>,
+    _phantom: PhantomData<(A, R)>,
+}
+
+impl<A, R> AdversarialEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    /// Creates a new AdversarialEnvironment.
+    ///
+    /// * `adversary` - Closure returning the reward of `action_id` at `step`, given the previously chosen action.
+    pub fn new<F>(adversary: F) -> Self
+    where
+        F: Fn(u32, usize, Option<u32>) -> f64 + Send + Sync + 'static,
+    {
+        AdversarialEnvironment {
+            adversary: Arc::new(adversary),
+            state: Mutex::new(AdversaryState::default()),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the current step and the previously chosen action.
+    fn step_and_previous(&self) -> (usize, Option<u32>) {
+        let state = self.state.lock().unwrap();
+        (
+            state.steps_started.saturating_sub(1),
+            state.previous_action_id,
+        )
+    }
+}
+
+impl<A, R> Clone for AdversarialEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    /// Clones the environment, sharing the adversary but restarting the episode at step 0.
+    fn clone(&self) -> Self {
+        AdversarialEnvironment {
+            adversary: Arc::clone(&self.adversary),
+            state: Mutex::new(AdversaryState::default()),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R> Environment<A, R, DummyContext> for AdversarialEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    /// Starts a new step.
+    fn get_context(&self) -> DummyContext {
+        let mut state = self.state.lock().unwrap();
+        state.steps_started += 1;
+        state.previous_action_id = state.current_action_id.take().or(state.previous_action_id);
+        DummyContext
+    }
+
+    /// Returns the adversary's reward for the given action and records it as this step's choice.
+    fn get_reward(&self, action: &A, _context: &DummyContext) -> R {
+        let (step, previous_action_id) = self.step_and_previous();
+        self.state.lock().unwrap().current_action_id = Some(action.id());
+        R::from((self.adversary)(action.id(), step, previous_action_id))
+    }
+
+    /// Returns the best reward the adversary offers among the provided actions at this step.
+    fn get_optimal_reward(&self, _context: &DummyContext, actions: &[A]) -> R {
+        let (step, previous_action_id) = self.step_and_previous();
+        let best = actions
+            .iter()
+            .map(|action| (self.adversary)(action.id(), step, previous_action_id))
+            .fold(f64::NEG_INFINITY, f64::max);
+        assert!(best.is_finite(), "No actions provided");
+        R::from(best)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::epsilon_greedy::EpsilonGreedyPolicy;
+    use crate::simulation::simulator::Simulator;
+    use crate::traits::entities::NumericAction;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl From<f64> for DummyReward {
+        fn from(value: f64) -> Self {
+            DummyReward(value)
+        }
+    }
+
+    fn punish_repeats() -> AdversarialEnvironment<NumericAction<i32>, DummyReward> {
+        AdversarialEnvironment::new(|action_id, _step, previous_action_id| {
+            if previous_action_id == Some(action_id) {
+                0.0
+            } else {
+                1.0
+            }
+        })
+    }
+
+    #[test]
+    fn test_adversarial_tracks_steps_and_previous_choice() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorder = Arc::clone(&seen);
+        let env: AdversarialEnvironment<NumericAction<i32>, DummyReward> =
+            AdversarialEnvironment::new(move |action_id, step, previous_action_id| {
+                recorder.lock().unwrap().push((action_id, step, previous_action_id));
+                step as f64
+            });
+        let actions = vec![
+            NumericAction::with_id(0, 0, "a0"),
+            NumericAction::with_id(1, 1, "a1"),
+        ];
+
+        let ctx = env.get_context();
+        assert_eq!(env.get_reward(&actions[1], &ctx), DummyReward(0.0));
+        let ctx = env.get_context();
+        assert_eq!(env.get_reward(&actions[0], &ctx), DummyReward(1.0));
+        assert_eq!(env.get_optimal_reward(&ctx, &actions), DummyReward(1.0));
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                (1, 0, None),
+                (0, 1, Some(1)),
+                (0, 1, Some(1)),
+                (1, 1, Some(1))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_adversary_punishing_repeats_beats_greedy() {
+        let actions = vec![
+            NumericAction::with_id(0, 0, "a0"),
+            NumericAction::with_id(1, 1, "a1"),
+        ];
+        let greedy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.0, &actions,
+        )
+        .unwrap();
+        let uniform = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            1.0, &actions,
+        )
+        .unwrap();
+
+        // Greedy keeps repeating its first arm, so the adversary zeroes every reward after step 0.
+        let greedy_results = Simulator::new(greedy, punish_repeats()).run_episode(1000, &actions);
+        assert_eq!(greedy_results.cumulative_reward, 1.0);
+        assert_eq!(greedy_results.final_simple_regret(), 999.0);
+
+        // Randomizing defeats the adversary about half of the time.
+        let uniform_results = Simulator::new(uniform, punish_repeats()).run_episode(1000, &actions);
+        assert!(uniform_results.cumulative_reward > 400.0);
+        assert!(uniform_results.final_simple_regret() < greedy_results.final_simple_regret());
+    }
+}
@@ -0,0 +1,335 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+use std::fs;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::traits::entities::{Action, DummyContext, Reward};
+use crate::traits::environment::{Environment, KnownMeansEnvironment};
+use crate::utils::error::OctopusError;
+
+/// Bernoulli reward environment for binary outcomes (e.g. click / no click).
+///
+/// Each action yields a reward of 1.0 with a per-arm success probability and 0.0 otherwise,
+/// keyed by action ID. The environment is non-contextual and always serves a `DummyContext`.
+#[derive(Debug)]
+pub struct BernoulliEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    probabilities: HashMap<u32, f64>,
+    seed: u64,
+    rng: Mutex<StdRng>,
+    _phantom: PhantomData<(A, R)>,
+}
+
+impl<A, R> BernoulliEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    /// Creates a new BernoulliEnvironment with seeded RNG.
+    ///
+    /// * `probabilities` - Success probability for each action ID.
+    /// * `seed` - Seed for the reward sampler.
+    ///
+    /// Returns an error if `probabilities` is empty or if any probability is outside `[0, 1]`.
+    pub fn new(probabilities: HashMap<u32, f64>, seed: u64) -> Result<Self, OctopusError> {
+        if probabilities.is_empty() {
+            return Err(OctopusError::EmptyCollection {
+                collection_name: "probabilities".to_string(),
+            });
+        }
+        if let Some((action_id, probability)) =
+            probabilities.iter().find(|(_, p)| !(0.0..=1.0).contains(*p))
+        {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: format!("probability[{}]", action_id),
+                value: probability.to_string(),
+                expected_range: "0.0 to 1.0 inclusive".to_string(),
+            });
+        }
+
+        Ok(BernoulliEnvironment {
+            probabilities,
+            seed,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Loads a BernoulliEnvironment from a CSV file of `arm_name,probability` rows.
+    ///
+    /// Each of `actions` takes the probability of the row matching its name; rows naming no action
+    /// are ignored. Blank lines are skipped, as is an optional `arm_name,probability` header on the
+    /// first line. Returns `OctopusError::Io` if the file cannot be read, `OctopusError::MalformedRow`
+    /// (with the 1-based line number) for rows that do not parse or hold a probability outside
+    /// `[0, 1]`, and `OctopusError::InvalidParameter` if two actions share a name or an action has
+    /// no row.
+    pub fn from_csv(
+        path: impl AsRef<Path>,
+        actions: &[A],
+        seed: u64,
+    ) -> Result<Self, OctopusError> {
+        let named_probabilities = read_probabilities_csv(path.as_ref())?;
+
+        let mut probabilities = HashMap::new();
+        let mut seen_names = HashMap::new();
+        for action in actions {
+            let arm_name = action.name();
+            if let Some(other_id) = seen_names.insert(arm_name.clone(), action.id()) {
+                return Err(OctopusError::InvalidParameter {
+                    parameter_name: format!("actions[{}].name", action.id()),
+                    value: arm_name,
+                    expected_range: format!("name not shared with action {}", other_id),
+                });
+            }
+            let probability = named_probabilities.get(&arm_name).ok_or_else(|| {
+                OctopusError::InvalidParameter {
+                    parameter_name: format!("probability[{}]", arm_name),
+                    value: "missing".to_string(),
+                    expected_range: "row in the CSV file".to_string(),
+                }
+            })?;
+            probabilities.insert(action.id(), *probability);
+        }
+
+        Self::new(probabilities, seed)
+    }
+
+    /// Returns the success probability configured for the given action ID, if any.
+    pub fn probability(&self, action_id: u32) -> Option<f64> {
+        self.probabilities.get(&action_id).copied()
+    }
+}
+
+/// Reads `arm_name,probability` rows into a map from arm name to probability.
+fn read_probabilities_csv(path: &Path) -> Result<HashMap<String, f64>, OctopusError> {
+    let contents = fs::read_to_string(path).map_err(|err| OctopusError::Io {
+        path: path.display().to_string(),
+        message: err.to_string(),
+    })?;
+
+    let mut probabilities = HashMap::new();
+    for (index, row) in contents.lines().enumerate() {
+        let line = index + 1;
+        let row = row.trim();
+        if row.is_empty() || (line == 1 && row == "arm_name,probability") {
+            continue;
+        }
+        let malformed = |reason: String| OctopusError::MalformedRow { line, reason };
+
+        let (arm_name, probability) = row.split_once(',').ok_or_else(|| {
+            malformed(format!("expected 'arm_name,probability', found '{}'", row))
+        })?;
+        let arm_name = arm_name.trim();
+        if arm_name.is_empty() {
+            return Err(malformed("arm name is empty".to_string()));
+        }
+        let probability: f64 = probability
+            .trim()
+            .parse()
+            .map_err(|_| malformed(format!("'{}' is not a number", probability.trim())))?;
+        if !(0.0..=1.0).contains(&probability) {
+            return Err(malformed(format!(
+                "probability {} is outside [0, 1]",
+                probability
+            )));
+        }
+        if probabilities.insert(arm_name.to_string(), probability).is_some() {
+            return Err(malformed(format!("duplicate arm name '{}'", arm_name)));
+        }
+    }
+
+    Ok(probabilities)
+}
+
+impl<A, R> Clone for BernoulliEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    /// Clones the environment, restarting the reward sampler from the original seed.
+    fn clone(&self) -> Self {
+        BernoulliEnvironment {
+            probabilities: self.probabilities.clone(),
+            seed: self.seed,
+            rng: Mutex::new(StdRng::seed_from_u64(self.seed)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R> Environment<A, R, DummyContext> for BernoulliEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    fn get_context(&self) -> DummyContext {
+        DummyContext
+    }
+
+    /// Draws a 0/1 reward for the given action.
+    ///
+    /// Panics if the action has no configured probability.
+    fn get_reward(&self, action: &A, _context: &DummyContext) -> R {
+        let probability = self
+            .probability(action.id())
+            .unwrap_or_else(|| panic!("No probability configured for action id {}", action.id()));
+        let mut rng = self.rng.lock().unwrap();
+        R::from(if rng.random_bool(probability) {
+            1.0
+        } else {
+            0.0
+        })
+    }
+
+    /// Returns the expected reward of the arm with the highest probability among the provided actions.
+    fn get_optimal_reward(&self, _context: &DummyContext, actions: &[A]) -> R {
+        let best_probability = actions
+            .iter()
+            .filter_map(|action| self.probability(action.id()))
+            .max_by(|p1, p2| p1.partial_cmp(p2).unwrap())
+            .expect("No actions with a configured probability provided");
+        R::from(best_probability)
+    }
+
+    fn known_means(&self) -> Option<&dyn KnownMeansEnvironment<A>> {
+        Some(self)
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+    }
+}
+
+impl<A, R> KnownMeansEnvironment<A> for BernoulliEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    /// Returns the success probability of the given action.
+    ///
+    /// Panics if the action has no configured probability.
+    fn true_mean(&self, action: &A) -> f64 {
+        self.probability(action.id())
+            .unwrap_or_else(|| panic!("No probability configured for action id {}", action.id()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::entities::NumericAction;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl From<f64> for DummyReward {
+        fn from(value: f64) -> Self {
+            DummyReward(value)
+        }
+    }
+
+    type TestEnv = BernoulliEnvironment<NumericAction<i32>, DummyReward>;
+
+    fn write_temp_csv(file_name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("{}-{}", std::process::id(), file_name));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn banner_actions() -> Vec<NumericAction<i32>> {
+        vec![
+            NumericAction::with_id(0, 0i32, "banner_a"),
+            NumericAction::with_id(1, 1i32, "banner_b"),
+        ]
+    }
+
+    #[test]
+    fn test_bernoulli_from_csv_valid() {
+        let path = write_temp_csv(
+            "bernoulli_valid.csv",
+            "arm_name,probability\nbanner_a,0.1\n\nbanner_b, 0.9\nbanner_c,0.5\n",
+        );
+        let actions = banner_actions();
+        let env = TestEnv::from_csv(&path, &actions, 42).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(env.probability(0), Some(0.1));
+        assert_eq!(env.probability(1), Some(0.9));
+        assert_eq!(env.probability(2), None);
+
+        let ctx = env.get_context();
+        for action in &actions {
+            let reward = env.get_reward(action, &ctx).value();
+            assert!(reward == 0.0 || reward == 1.0);
+        }
+        assert_eq!(env.get_optimal_reward(&ctx, &actions), DummyReward(0.9));
+    }
+
+    #[test]
+    fn test_bernoulli_from_csv_rejects_ambiguous_actions() {
+        let path = write_temp_csv("bernoulli_ambiguous.csv", "banner_a,0.1\nbanner_b,0.9\n");
+        let shared_name = vec![
+            NumericAction::with_id(0, 0i32, "banner_a"),
+            NumericAction::with_id(1, 1i32, "banner_a"),
+        ];
+        let err = TestEnv::from_csv(&path, &shared_name, 42).unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "actions[1].name".to_string(),
+                value: "banner_a".to_string(),
+                expected_range: "name not shared with action 0".to_string(),
+            }
+        );
+
+        let unlisted = vec![NumericAction::with_id(0, 0i32, "banner_c")];
+        let err = TestEnv::from_csv(&path, &unlisted, 42).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "probability[banner_c]".to_string(),
+                value: "missing".to_string(),
+                expected_range: "row in the CSV file".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_bernoulli_from_csv_malformed_probability() {
+        let path = write_temp_csv("bernoulli_malformed.csv", "banner_a,0.1\nbanner_b,1.5\n");
+        let err = TestEnv::from_csv(&path, &banner_actions(), 42).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            err,
+            OctopusError::MalformedRow {
+                line: 2,
+                reason: "probability 1.5 is outside [0, 1]".to_string(),
+            }
+        );
+
+        let path = write_temp_csv("bernoulli_not_a_number.csv", "banner_a,high\n");
+        let err = TestEnv::from_csv(&path, &banner_actions(), 42).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            err,
+            OctopusError::MalformedRow {
+                line: 1,
+                reason: "'high' is not a number".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand_distr::{Distribution, Normal};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::traits::entities::{Action, DummyContext, Reward};
+use crate::traits::environment::{Environment, KnownMeansEnvironment};
+use crate::utils::error::OctopusError;
+
+/// Gaussian reward environment for continuous outcomes (e.g. revenue or latency).
+///
+/// Each action draws its reward from a normal distribution with a per-arm mean and standard
+/// deviation, keyed by action ID. The environment is non-contextual and always serves a `DummyContext`.
+#[derive(Debug)]
+pub struct GaussianEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    arms: HashMap<u32, (f64, f64)>,
+    seed: u64,
+    rng: Mutex<StdRng>,
+    _phantom: PhantomData<(A, R)>,
+}
+
+impl<A, R> GaussianEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    /// Creates a new GaussianEnvironment with seeded RNG.
+    ///
+    /// * `arms` - `(mean, std_dev)` for each action ID.
+    /// * `seed` - Seed for the reward sampler.
+    ///
+    /// Returns an error if `arms` is empty, if any mean is not finite, or if any standard deviation
+    /// is negative or not finite.
+    pub fn new(arms: HashMap<u32, (f64, f64)>, seed: u64) -> Result<Self, OctopusError> {
+        if arms.is_empty() {
+            return Err(OctopusError::EmptyCollection {
+                collection_name: "arms".to_string(),
+            });
+        }
+        for (action_id, &(mean, std_dev)) in &arms {
+            if !mean.is_finite() {
+                return Err(OctopusError::InvalidParameter {
+                    parameter_name: format!("mean[{}]", action_id),
+                    value: mean.to_string(),
+                    expected_range: "finite value".to_string(),
+                });
+            }
+            if !(std_dev.is_finite() && std_dev >= 0.0) {
+                return Err(OctopusError::InvalidParameter {
+                    parameter_name: format!("std_dev[{}]", action_id),
+                    value: std_dev.to_string(),
+                    expected_range: "finite value greater than or equal to 0.0".to_string(),
+                });
+            }
+        }
+
+        Ok(GaussianEnvironment {
+            arms,
+            seed,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the mean reward configured for the given action ID, if any.
+    pub fn mean(&self, action_id: u32) -> Option<f64> {
+        self.arms.get(&action_id).map(|&(mean, _)| mean)
+    }
+
+    /// Returns the standard deviation configured for the given action ID, if any.
+    pub fn std_dev(&self, action_id: u32) -> Option<f64> {
+        self.arms.get(&action_id).map(|&(_, std_dev)| std_dev)
+    }
+}
+
+impl<A, R> Clone for GaussianEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    /// Clones the environment, restarting the reward sampler from the original seed.
+    fn clone(&self) -> Self {
+        GaussianEnvironment {
+            arms: self.arms.clone(),
+            seed: self.seed,
+            rng: Mutex::new(StdRng::seed_from_u64(self.seed)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R> Environment<A, R, DummyContext> for GaussianEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    fn get_context(&self) -> DummyContext {
+        DummyContext
+    }
+
+    /// Draws a normally distributed reward for the given action.
+    ///
+    /// Panics if the action has no configured arm.
+    fn get_reward(&self, action: &A, _context: &DummyContext) -> R {
+        let &(mean, std_dev) = self
+            .arms
+            .get(&action.id())
+            .unwrap_or_else(|| panic!("No arm configured for action id {}", action.id()));
+        let normal = Normal::new(mean, std_dev).expect("Standard deviation must be finite.");
+        let mut rng = self.rng.lock().unwrap();
+        R::from(normal.sample(&mut *rng))
+    }
+
+    /// Returns the expected reward of the arm with the highest mean among the provided actions.
+    fn get_optimal_reward(&self, _context: &DummyContext, actions: &[A]) -> R {
+        let best_mean = actions
+            .iter()
+            .filter_map(|action| self.mean(action.id()))
+            .max_by(|m1, m2| m1.partial_cmp(m2).unwrap())
+            .expect("No actions with a configured arm provided");
+        R::from(best_mean)
+    }
+
+    fn known_means(&self) -> Option<&dyn KnownMeansEnvironment<A>> {
+        Some(self)
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+    }
+}
+
+impl<A, R> KnownMeansEnvironment<A> for GaussianEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    /// Returns the mean of the given action.
+    ///
+    /// Panics if the action has no configured arm.
+    fn true_mean(&self, action: &A) -> f64 {
+        self.mean(action.id())
+            .unwrap_or_else(|| panic!("No arm configured for action id {}", action.id()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::entities::NumericAction;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl From<f64> for DummyReward {
+        fn from(value: f64) -> Self {
+            DummyReward(value)
+        }
+    }
+
+    fn build_env(
+        arms: &[(u32, (f64, f64))],
+    ) -> Result<GaussianEnvironment<NumericAction<i32>, DummyReward>, OctopusError> {
+        GaussianEnvironment::new(arms.iter().copied().collect(), 42)
+    }
+
+    #[test]
+    fn test_gaussian_init_errors() {
+        let err = build_env(&[]).unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::EmptyCollection {
+                collection_name: "arms".to_string()
+            }
+        );
+
+        let err = build_env(&[(3, (1.0, -0.5))]).unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "std_dev[3]".to_string(),
+                value: "-0.5".to_string(),
+                expected_range: "finite value greater than or equal to 0.0".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_gaussian_empirical_mean_and_known_means() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "low"),
+            NumericAction::with_id(1, 1i32, "high"),
+        ];
+        let env = build_env(&[(0, (-1.0, 2.0)), (1, (3.0, 0.5))]).unwrap();
+        let ctx = env.get_context();
+
+        let num_samples = 20_000;
+        for action in &actions {
+            let mean = env.mean(action.id()).unwrap();
+            let std_dev = env.std_dev(action.id()).unwrap();
+            let total: f64 = (0..num_samples).map(|_| env.get_reward(action, &ctx).value()).sum();
+            let empirical_mean = total / num_samples as f64;
+            let tolerance = 5.0 * std_dev / (num_samples as f64).sqrt();
+            assert!(
+                (empirical_mean - mean).abs() < tolerance,
+                "mean: {}, empirical mean: {}",
+                mean,
+                empirical_mean
+            );
+        }
+
+        let known = env.known_means().unwrap();
+        assert_eq!(known.true_mean(&actions[0]), -1.0);
+        assert_eq!(known.true_mean(&actions[1]), 3.0);
+        assert_eq!(env.get_optimal_reward(&ctx, &actions), DummyReward(3.0));
+    }
+}
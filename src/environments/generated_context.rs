@@ -0,0 +1,198 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+use crate::traits::entities::{Action, Context, Reward};
+use crate::traits::environment::Environment;
+
+/// Reward of an action in a given context.
+type RewardFn<A, C> = Arc<dyn Fn(&A, &C) -> f64 + Send + Sync>;
+
+/// Contextual environment whose contexts are produced by a seeded generator.
+///
+/// Each `get_context` call advances the generator with the environment's RNG to produce the next
+/// context, and rewards are computed by a deterministic reward function of the action and context.
+/// Cloning restarts both the generator and its RNG, so every run replays the same context sequence.
+pub struct GeneratedContextEnvironment<A, R, C, G>
+where
+    A: Action,
+    R: Reward + From<f64>,
+    C: Context,
+    G: FnMut(&mut StdRng) -> C + Clone + Send + Sync + 'static,
+{
+    initial_generator: G,
+    generator: Mutex<G>,
+    reward_fn: RewardFn<A, C>,
+    seed: u64,
+    rng: Mutex<StdRng>,
+    _phantom: PhantomData<R>,
+}
+
+impl<A, R, C, G> GeneratedContextEnvironment<A, R, C, G>
+where
+    A: Action,
+    R: Reward + From<f64>,
+    C: Context,
+    G: FnMut(&mut StdRng) -> C + Clone + Send + Sync + 'static,
+{
+    /// Creates a new GeneratedContextEnvironment with seeded RNG.
+    ///
+    /// * `generator` - Produces the next context from the environment's RNG.
+    /// * `reward_fn` - Reward of an action in a given context.
+    /// * `seed` - Seed for the generator's RNG.
+    pub fn new<F>(generator: G, reward_fn: F, seed: u64) -> Self
+    where
+        F: Fn(&A, &C) -> f64 + Send + Sync + 'static,
+    {
+        GeneratedContextEnvironment {
+            initial_generator: generator.clone(),
+            generator: Mutex::new(generator),
+            reward_fn: Arc::new(reward_fn),
+            seed,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R, C, G> Clone for GeneratedContextEnvironment<A, R, C, G>
+where
+    A: Action,
+    R: Reward + From<f64>,
+    C: Context,
+    G: FnMut(&mut StdRng) -> C + Clone + Send + Sync + 'static,
+{
+    /// Clones the environment, restarting the generator and its RNG from the original seed.
+    fn clone(&self) -> Self {
+        GeneratedContextEnvironment {
+            initial_generator: self.initial_generator.clone(),
+            generator: Mutex::new(self.initial_generator.clone()),
+            reward_fn: Arc::clone(&self.reward_fn),
+            seed: self.seed,
+            rng: Mutex::new(StdRng::seed_from_u64(self.seed)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R, C, G> Environment<A, R, C> for GeneratedContextEnvironment<A, R, C, G>
+where
+    A: Action,
+    R: Reward + From<f64>,
+    C: Context,
+    G: FnMut(&mut StdRng) -> C + Clone + Send + Sync + 'static,
+{
+    /// Generates the next context.
+    fn get_context(&self) -> C {
+        let mut rng = self.rng.lock().unwrap();
+        (self.generator.lock().unwrap())(&mut rng)
+    }
+
+    fn get_reward(&self, action: &A, context: &C) -> R {
+        R::from((self.reward_fn)(action, context))
+    }
+
+    /// Restarts the generator from its initial state and reseeds the RNG.
+    fn reseed(&mut self, seed: u64) {
+        *self.generator.get_mut().unwrap() = self.initial_generator.clone();
+        self.seed = seed;
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::entities::NumericAction;
+    use ndarray::{Array, Array1, Ix1};
+    use rand::Rng;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl From<f64> for DummyReward {
+        fn from(value: f64) -> Self {
+            DummyReward(value)
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct UserContext {
+        step: usize,
+        affinity: f64,
+    }
+
+    impl Context for UserContext {
+        type DimType = Ix1;
+        fn to_ndarray(&self) -> Array<f64, Self::DimType> {
+            Array1::from_vec(vec![self.step as f64, self.affinity])
+        }
+    }
+
+    fn build_env() -> GeneratedContextEnvironment<
+        NumericAction<i32>,
+        DummyReward,
+        UserContext,
+        impl FnMut(&mut StdRng) -> UserContext + Clone + Send + Sync + 'static,
+    > {
+        let mut step = 0;
+        GeneratedContextEnvironment::new(
+            move |rng: &mut StdRng| {
+                step += 1;
+                UserContext {
+                    step,
+                    affinity: rng.random::<f64>(),
+                }
+            },
+            |action: &NumericAction<i32>, ctx: &UserContext| action.value() as f64 * ctx.affinity,
+            42,
+        )
+    }
+
+    #[test]
+    fn test_generated_contexts_vary_and_reproduce() {
+        let env = build_env();
+        let contexts: Vec<UserContext> = (0..10).map(|_| env.get_context()).collect();
+        assert_eq!(
+            contexts.iter().map(|ctx| ctx.step).collect::<Vec<_>>(),
+            (1..=10).collect::<Vec<_>>()
+        );
+        assert!(contexts.windows(2).all(|pair| pair[0].affinity != pair[1].affinity));
+
+        // A clone restarts the generator, and an identically seeded environment matches it.
+        let cloned = env.clone();
+        let replayed: Vec<UserContext> = (0..10).map(|_| cloned.get_context()).collect();
+        assert_eq!(replayed, contexts);
+        let rebuilt = build_env();
+        let rebuilt_contexts: Vec<UserContext> = (0..10).map(|_| rebuilt.get_context()).collect();
+        assert_eq!(rebuilt_contexts, contexts);
+
+        let action = NumericAction::with_id(0, 2i32, "double");
+        assert_eq!(
+            env.get_reward(&action, &contexts[0]),
+            DummyReward(2.0 * contexts[0].affinity)
+        );
+    }
+
+    #[test]
+    fn test_reseed_restarts_the_generator() {
+        let mut env = build_env();
+        let contexts: Vec<UserContext> = (0..5).map(|_| env.get_context()).collect();
+
+        env.reseed(42);
+        let replayed: Vec<UserContext> = (0..5).map(|_| env.get_context()).collect();
+        assert_eq!(replayed, contexts);
+
+        env.reseed(7);
+        let reseeded: Vec<UserContext> = (0..5).map(|_| env.get_context()).collect();
+        assert_eq!(reseeded[0].step, 1);
+        assert_ne!(reseeded, contexts);
+    }
+}
@@ -0,0 +1,19 @@
+//! Reward environments for simulating bandit problems.
+//!
+//! This module contains ready-made implementations of the Environment trait:
+//!
+//! - `bernoulli`, `gaussian` and `poisson`: stochastic arms with binary, continuous and count rewards.
+//! - `adversarial`: closure-driven rewards for worst-case testing.
+//! - `generated_context`: contexts drawn from a seeded generator.
+//! - `replay`: rewards replayed from logged interactions.
+//! - `noisy`: a wrapper adding Gaussian observation noise to any environment's rewards.
+//!
+//! Environments are generic over action and reward types; rewards are built from sampled values via `From<f64>`.
+
+pub mod adversarial;
+pub mod bernoulli;
+pub mod gaussian;
+pub mod generated_context;
+pub mod noisy;
+pub mod poisson;
+pub mod replay;
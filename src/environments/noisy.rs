@@ -0,0 +1,240 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand_distr::{Distribution, Normal};
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::traits::entities::{Action, Context, Reward};
+use crate::traits::environment::{Environment, KnownMeansEnvironment};
+use crate::utils::error::OctopusError;
+
+/// Environment wrapper that adds zero-mean Gaussian noise to every reward of an inner environment.
+///
+/// Only `get_reward` is perturbed: contexts, `get_optimal_reward`, `reward_scale` and the known
+/// means are delegated unchanged, so regret is still measured against the noiseless problem and
+/// the effect of observation noise on a policy can be studied in isolation. Note that noisy
+/// rewards may fall outside the inner environment's `reward_scale`.
+#[derive(Debug)]
+pub struct NoisyEnvironment<E, A, R, C>
+where
+    E: Environment<A, R, C>,
+    A: Action,
+    R: Reward + From<f64>,
+    C: Context,
+{
+    inner: E,
+    noise_std_dev: f64,
+    seed: u64,
+    rng: Mutex<StdRng>,
+    _phantom: PhantomData<(A, R, C)>,
+}
+
+impl<E, A, R, C> NoisyEnvironment<E, A, R, C>
+where
+    E: Environment<A, R, C>,
+    A: Action,
+    R: Reward + From<f64>,
+    C: Context,
+{
+    /// Creates a new NoisyEnvironment with seeded RNG.
+    ///
+    /// * `inner` - Environment whose rewards are perturbed.
+    /// * `noise_std_dev` - Standard deviation of the Gaussian noise added to each reward.
+    /// * `seed` - Seed for the noise sampler.
+    ///
+    /// Returns an error if `noise_std_dev` is negative or not finite.
+    pub fn new(inner: E, noise_std_dev: f64, seed: u64) -> Result<Self, OctopusError> {
+        if !(noise_std_dev.is_finite() && noise_std_dev >= 0.0) {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "noise_std_dev".to_string(),
+                value: noise_std_dev.to_string(),
+                expected_range: "finite value greater than or equal to 0.0".to_string(),
+            });
+        }
+        Ok(NoisyEnvironment {
+            inner,
+            noise_std_dev,
+            seed,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the wrapped environment.
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    /// Returns the standard deviation of the added noise.
+    pub fn noise_std_dev(&self) -> f64 {
+        self.noise_std_dev
+    }
+}
+
+impl<E, A, R, C> Clone for NoisyEnvironment<E, A, R, C>
+where
+    E: Environment<A, R, C>,
+    A: Action,
+    R: Reward + From<f64>,
+    C: Context,
+{
+    /// Clones the environment, cloning the inner one and restarting the noise sampler from the original seed.
+    fn clone(&self) -> Self {
+        NoisyEnvironment {
+            inner: self.inner.clone(),
+            noise_std_dev: self.noise_std_dev,
+            seed: self.seed,
+            rng: Mutex::new(StdRng::seed_from_u64(self.seed)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E, A, R, C> Environment<A, R, C> for NoisyEnvironment<E, A, R, C>
+where
+    E: Environment<A, R, C>,
+    A: Action,
+    R: Reward + From<f64>,
+    C: Context,
+{
+    fn get_context(&self) -> C {
+        self.inner.get_context()
+    }
+
+    /// Returns the inner environment's reward plus a draw from `Normal(0, noise_std_dev)`.
+    fn get_reward(&self, action: &A, context: &C) -> R {
+        let reward = self.inner.get_reward(action, context).value();
+        if self.noise_std_dev == 0.0 {
+            return R::from(reward);
+        }
+        let normal = Normal::new(0.0, self.noise_std_dev).expect("Noise std dev must be finite.");
+        let mut rng = self.rng.lock().unwrap();
+        R::from(reward + normal.sample(&mut *rng))
+    }
+
+    /// Delegates to the inner environment, so the optimal reward stays noiseless.
+    fn get_optimal_reward(&self, context: &C, actions: &[A]) -> R {
+        self.inner.get_optimal_reward(context, actions)
+    }
+
+    fn reward_scale(&self) -> (f64, f64) {
+        self.inner.reward_scale()
+    }
+
+    /// Delegates to the inner environment; the noise has zero mean, so the arm means are unchanged.
+    fn known_means(&self) -> Option<&dyn KnownMeansEnvironment<A>> {
+        self.inner.known_means()
+    }
+
+    /// Reseeds the inner environment with `seed` and the noise sampler with its bitwise complement,
+    /// so rewards and noise stay independent.
+    fn reseed(&mut self, seed: u64) {
+        self.inner.reseed(seed);
+        self.seed = !seed;
+        self.rng = Mutex::new(StdRng::seed_from_u64(self.seed));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::epsilon_greedy::EpsilonGreedyPolicy;
+    use crate::environments::gaussian::GaussianEnvironment;
+    use crate::traits::entities::{DummyContext, NumericAction};
+    use crate::traits::policy::BanditPolicy;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl From<f64> for DummyReward {
+        fn from(value: f64) -> Self {
+            DummyReward(value)
+        }
+    }
+
+    type InnerEnv = GaussianEnvironment<NumericAction<i32>, DummyReward>;
+
+    fn build_inner(std_dev: f64) -> InnerEnv {
+        GaussianEnvironment::new([(0, (0.2, std_dev)), (1, (0.8, std_dev))].into(), 42).unwrap()
+    }
+
+    fn build_actions() -> Vec<NumericAction<i32>> {
+        vec![
+            NumericAction::with_id(0, 0i32, "low"),
+            NumericAction::with_id(1, 1i32, "high"),
+        ]
+    }
+
+    #[test]
+    fn test_noisy_init_errors() {
+        let err = NoisyEnvironment::new(build_inner(0.1), -1.0, 7).unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "noise_std_dev".to_string(),
+                value: "-1".to_string(),
+                expected_range: "finite value greater than or equal to 0.0".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_noisy_zero_noise_matches_inner() {
+        let actions = build_actions();
+        let inner = build_inner(0.3);
+        let noisy = NoisyEnvironment::new(inner.clone(), 0.0, 7).unwrap();
+        let ctx = DummyContext;
+
+        for step in 0..100 {
+            let action = &actions[step % actions.len()];
+            assert_eq!(
+                noisy.get_reward(action, &ctx),
+                inner.get_reward(action, &ctx)
+            );
+        }
+        assert_eq!(
+            noisy.get_optimal_reward(&ctx, &actions),
+            inner.get_optimal_reward(&ctx, &actions)
+        );
+    }
+
+    #[test]
+    fn test_noisy_estimates_converge_to_true_means() {
+        let actions = build_actions();
+        let noisy = NoisyEnvironment::new(build_inner(0.0), 1.0, 7).unwrap();
+        let ctx = noisy.get_context();
+        // The optimal reward ignores the noise.
+        assert_eq!(noisy.get_optimal_reward(&ctx, &actions), DummyReward(0.8));
+
+        // Pure exploration keeps both arms sampled evenly.
+        let mut policy =
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new_seeded(
+                1.0, &actions, 3,
+            )
+            .unwrap();
+        let num_steps = 20_000;
+        for _ in 0..num_steps {
+            policy.choose_and_update(&ctx, |action| noisy.get_reward(action, &ctx));
+        }
+
+        let known = noisy.known_means().unwrap();
+        for action in &actions {
+            let estimate = policy.expected_reward(action).unwrap();
+            let true_mean = known.true_mean(action);
+            // About num_steps / 2 pulls per arm, each with unit noise.
+            let tolerance = 5.0 / (num_steps as f64 / 2.0).sqrt();
+            assert!(
+                (estimate - true_mean).abs() < tolerance,
+                "true mean: {}, estimate: {}",
+                true_mean,
+                estimate
+            );
+        }
+    }
+}
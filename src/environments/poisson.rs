@@ -0,0 +1,226 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand_distr::{Distribution, Poisson};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::traits::entities::{Action, DummyContext, Reward};
+use crate::traits::environment::{Environment, KnownMeansEnvironment};
+use crate::utils::error::OctopusError;
+
+/// Poisson reward environment for count-based outcomes (e.g. number of clicks).
+///
+/// Each action draws its reward from a Poisson distribution with a per-arm rate `lambda`,
+/// keyed by action ID. The environment is non-contextual and always serves a `DummyContext`.
+#[derive(Debug)]
+pub struct PoissonEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    lambdas: HashMap<u32, f64>,
+    seed: u64,
+    rng: Mutex<StdRng>,
+    _phantom: PhantomData<(A, R)>,
+}
+
+impl<A, R> PoissonEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    /// Creates a new PoissonEnvironment with seeded RNG.
+    ///
+    /// * `lambdas` - Poisson rate for each action ID.
+    /// * `seed` - Seed for the reward sampler.
+    ///
+    /// Returns an error if `lambdas` is empty or if any rate is not strictly positive and finite.
+    pub fn new(lambdas: HashMap<u32, f64>, seed: u64) -> Result<Self, OctopusError> {
+        if lambdas.is_empty() {
+            return Err(OctopusError::EmptyCollection {
+                collection_name: "lambdas".to_string(),
+            });
+        }
+        if let Some((action_id, lambda)) =
+            lambdas.iter().find(|(_, lambda)| !(lambda.is_finite() && **lambda > 0.0))
+        {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: format!("lambda[{}]", action_id),
+                value: lambda.to_string(),
+                expected_range: "finite value greater than 0.0".to_string(),
+            });
+        }
+
+        Ok(PoissonEnvironment {
+            lambdas,
+            seed,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the Poisson rate configured for the given action ID, if any.
+    pub fn lambda(&self, action_id: u32) -> Option<f64> {
+        self.lambdas.get(&action_id).copied()
+    }
+}
+
+impl<A, R> Clone for PoissonEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    /// Clones the environment, restarting the reward sampler from the original seed.
+    fn clone(&self) -> Self {
+        PoissonEnvironment {
+            lambdas: self.lambdas.clone(),
+            seed: self.seed,
+            rng: Mutex::new(StdRng::seed_from_u64(self.seed)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R> Environment<A, R, DummyContext> for PoissonEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    fn get_context(&self) -> DummyContext {
+        DummyContext
+    }
+
+    /// Draws a Poisson-distributed count for the given action.
+    ///
+    /// Panics if the action has no configured rate.
+    fn get_reward(&self, action: &A, _context: &DummyContext) -> R {
+        let lambda = self
+            .lambda(action.id())
+            .unwrap_or_else(|| panic!("No lambda configured for action id {}", action.id()));
+        let poisson = Poisson::new(lambda).expect("Poisson rate must be positive and finite.");
+        let mut rng = self.rng.lock().unwrap();
+        R::from(poisson.sample(&mut *rng))
+    }
+
+    /// Returns the expected reward of the arm with the highest rate among the provided actions.
+    fn get_optimal_reward(&self, _context: &DummyContext, actions: &[A]) -> R {
+        let best_lambda = actions
+            .iter()
+            .filter_map(|action| self.lambda(action.id()))
+            .max_by(|l1, l2| l1.partial_cmp(l2).unwrap())
+            .expect("No actions with a configured lambda provided");
+        R::from(best_lambda)
+    }
+
+    fn known_means(&self) -> Option<&dyn KnownMeansEnvironment<A>> {
+        Some(self)
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+    }
+}
+
+impl<A, R> KnownMeansEnvironment<A> for PoissonEnvironment<A, R>
+where
+    A: Action,
+    R: Reward + From<f64>,
+{
+    /// Returns the Poisson rate of the given action.
+    ///
+    /// Panics if the action has no configured rate.
+    fn true_mean(&self, action: &A) -> f64 {
+        self.lambda(action.id())
+            .unwrap_or_else(|| panic!("No lambda configured for action id {}", action.id()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::entities::NumericAction;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl From<f64> for DummyReward {
+        fn from(value: f64) -> Self {
+            DummyReward(value)
+        }
+    }
+
+    fn build_env(
+        lambdas: &[(u32, f64)],
+    ) -> Result<PoissonEnvironment<NumericAction<i32>, DummyReward>, OctopusError> {
+        PoissonEnvironment::new(lambdas.iter().copied().collect(), 42)
+    }
+
+    #[test]
+    fn test_poisson_init_errors() {
+        let err = build_env(&[]).unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::EmptyCollection {
+                collection_name: "lambdas".to_string()
+            }
+        );
+
+        let err = build_env(&[(0, 1.0), (1, -2.0)]).unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "lambda[1]".to_string(),
+                value: "-2".to_string(),
+                expected_range: "finite value greater than 0.0".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_poisson_empirical_mean_converges_to_lambda() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "low"),
+            NumericAction::with_id(1, 1i32, "high"),
+        ];
+        let env = build_env(&[(0, 1.5), (1, 7.0)]).unwrap();
+        let ctx = env.get_context();
+
+        let num_samples = 20_000;
+        for action in &actions {
+            let lambda = env.lambda(action.id()).unwrap();
+            let total: f64 = (0..num_samples).map(|_| env.get_reward(action, &ctx).value()).sum();
+            let empirical_mean = total / num_samples as f64;
+            // Standard error of the mean is sqrt(lambda / n); allow a generous margin.
+            let tolerance = 5.0 * (lambda / num_samples as f64).sqrt();
+            assert!(
+                (empirical_mean - lambda).abs() < tolerance,
+                "lambda: {}, empirical mean: {}",
+                lambda,
+                empirical_mean
+            );
+        }
+
+        assert_eq!(env.get_optimal_reward(&ctx, &actions), DummyReward(7.0));
+    }
+
+    #[test]
+    fn test_poisson_clone_restarts_sampler() {
+        let action = NumericAction::with_id(0, 0i32, "arm");
+        let env = build_env(&[(0, 3.0)]).unwrap();
+        let ctx = DummyContext;
+
+        let first: Vec<f64> = (0..10).map(|_| env.get_reward(&action, &ctx).value()).collect();
+        let cloned = env.clone();
+        let replayed: Vec<f64> =
+            (0..10).map(|_| cloned.get_reward(&action, &ctx).value()).collect();
+        assert_eq!(first, replayed);
+    }
+}
@@ -0,0 +1,281 @@
+use std::fs;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use crate::traits::entities::{Action, Context, Reward};
+use crate::traits::environment::Environment;
+use crate::utils::error::OctopusError;
+
+/// Logged `(context, action, reward)` records of a replay dataset.
+type ReplayRecords<A, R, C> = Arc<Vec<(C, A, R)>>;
+
+/// Environment that replays logged interactions, for offline evaluation from production logs.
+///
+/// Each `get_context` call serves the context of the next record, wrapping around to the first
+/// record after the last one. Playing the logged action of the current record returns its logged
+/// reward; any other action returns 0.0, as the log holds no feedback for it. Cloning restarts the
+/// replay from the first record.
+#[derive(Debug)]
+pub struct ReplayEnvironment<A, R, C>
+where
+    A: Action,
+    R: Reward + From<f64>,
+    C: Context,
+{
+    records: ReplayRecords<A, R, C>,
+    steps_started: Mutex<usize>,
+    _phantom: PhantomData<(A, R, C)>,
+}
+
+impl<A, R, C> ReplayEnvironment<A, R, C>
+where
+    A: Action,
+    R: Reward + From<f64>,
+    C: Context,
+{
+    /// Creates a new ReplayEnvironment from logged `(context, action, reward)` records, in replay order.
+    ///
+    /// Returns an error if `records` is empty.
+    pub fn new(records: Vec<(C, A, R)>) -> Result<Self, OctopusError> {
+        if records.is_empty() {
+            return Err(OctopusError::EmptyCollection {
+                collection_name: "records".to_string(),
+            });
+        }
+        Ok(ReplayEnvironment {
+            records: Arc::new(records),
+            steps_started: Mutex::new(0),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Loads a ReplayEnvironment from a CSV file of `context,action,reward` rows.
+    ///
+    /// The last two columns are the action and the reward; everything before them is passed to
+    /// `parse_context`, so the context may itself hold commas (e.g. a feature vector). Blank lines
+    /// are skipped, as is an optional `context,action,reward` header on the first line.
+    /// Returns `OctopusError::Io` if the file cannot be read, and `OctopusError::MalformedRow`
+    /// (with the 1-based line number) for rows with missing columns or rejected by a parser, whose
+    /// error message becomes the reason.
+    pub fn from_csv<PC, PA, PR>(
+        path: impl AsRef<Path>,
+        parse_context: PC,
+        parse_action: PA,
+        parse_reward: PR,
+    ) -> Result<Self, OctopusError>
+    where
+        PC: Fn(&str) -> Result<C, String>,
+        PA: Fn(&str) -> Result<A, String>,
+        PR: Fn(&str) -> Result<R, String>,
+    {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|err| OctopusError::Io {
+            path: path.display().to_string(),
+            message: err.to_string(),
+        })?;
+
+        let mut records = Vec::new();
+        for (index, row) in contents.lines().enumerate() {
+            let line = index + 1;
+            let row = row.trim();
+            if row.is_empty() || (line == 1 && row == "context,action,reward") {
+                continue;
+            }
+            let malformed = |reason: String| OctopusError::MalformedRow { line, reason };
+
+            let mut columns = row.rsplitn(3, ',');
+            let (Some(reward), Some(action), Some(context)) =
+                (columns.next(), columns.next(), columns.next())
+            else {
+                return Err(malformed(format!(
+                    "expected 'context,action,reward', found '{}'",
+                    row
+                )));
+            };
+            records.push((
+                parse_context(context.trim()).map_err(malformed)?,
+                parse_action(action.trim()).map_err(malformed)?,
+                parse_reward(reward.trim()).map_err(malformed)?,
+            ));
+        }
+
+        Self::new(records)
+    }
+
+    /// Returns the number of logged records.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns true if there are no logged records, which `new` rules out.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns the record served by the last `get_context` call, or the first one before any call.
+    fn current_record(&self) -> &(C, A, R) {
+        let steps_started = *self.steps_started.lock().unwrap();
+        &self.records[steps_started.saturating_sub(1) % self.records.len()]
+    }
+}
+
+impl<A, R, C> Clone for ReplayEnvironment<A, R, C>
+where
+    A: Action,
+    R: Reward + From<f64>,
+    C: Context,
+{
+    /// Clones the environment, sharing the records but restarting the replay at the first record.
+    fn clone(&self) -> Self {
+        ReplayEnvironment {
+            records: Arc::clone(&self.records),
+            steps_started: Mutex::new(0),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R, C> Environment<A, R, C> for ReplayEnvironment<A, R, C>
+where
+    A: Action,
+    R: Reward + From<f64>,
+    C: Context,
+{
+    /// Advances to the next record and returns its context.
+    fn get_context(&self) -> C {
+        let mut steps_started = self.steps_started.lock().unwrap();
+        *steps_started += 1;
+        self.records[(*steps_started - 1) % self.records.len()].0.clone()
+    }
+
+    /// Returns the logged reward if `action` is the current record's action, and 0.0 otherwise.
+    fn get_reward(&self, action: &A, _context: &C) -> R {
+        let (_, logged_action, logged_reward) = self.current_record();
+        if logged_action.id() == action.id() {
+            logged_reward.clone()
+        } else {
+            R::from(0.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::entities::NumericAction;
+    use ndarray::{Array1, Ix1};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl From<f64> for DummyReward {
+        fn from(value: f64) -> Self {
+            DummyReward(value)
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct FeatureContext(Vec<f64>);
+
+    impl Context for FeatureContext {
+        type DimType = Ix1;
+        fn to_ndarray(&self) -> Array1<f64> {
+            Array1::from_vec(self.0.clone())
+        }
+    }
+
+    type TestEnv = ReplayEnvironment<NumericAction<i32>, DummyReward, FeatureContext>;
+
+    fn write_temp_csv(file_name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("{}-{}", std::process::id(), file_name));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn load(path: &Path) -> Result<TestEnv, OctopusError> {
+        let actions = [
+            NumericAction::with_id(0, 0i32, "a0"),
+            NumericAction::with_id(1, 1i32, "a1"),
+        ];
+        TestEnv::from_csv(
+            path,
+            |context| {
+                context
+                    .split(',')
+                    .map(|x| x.trim().parse().map_err(|_| format!("bad feature '{}'", x)))
+                    .collect::<Result<Vec<f64>, String>>()
+                    .map(FeatureContext)
+            },
+            |action| {
+                action
+                    .parse::<u32>()
+                    .ok()
+                    .and_then(|id| actions.iter().find(|a| a.id() == id).cloned())
+                    .ok_or_else(|| format!("unknown action '{}'", action))
+            },
+            |reward| {
+                reward.parse().map(DummyReward).map_err(|_| format!("bad reward '{}'", reward))
+            },
+        )
+    }
+
+    #[test]
+    fn test_replay_from_csv_round_trip() {
+        let path = write_temp_csv(
+            "replay_valid.csv",
+            "context,action,reward\n0.5,1.0,1,0.7\n\n-2.0,0.0,0,0.25\n",
+        );
+        let env = load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(env.len(), 2);
+
+        let a0 = NumericAction::with_id(0, 0i32, "a0");
+        let a1 = NumericAction::with_id(1, 1i32, "a1");
+
+        let ctx = env.get_context();
+        assert_eq!(ctx, FeatureContext(vec![0.5, 1.0]));
+        assert_eq!(env.get_reward(&a1, &ctx), DummyReward(0.7));
+        assert_eq!(env.get_reward(&a0, &ctx), DummyReward(0.0));
+
+        let ctx = env.get_context();
+        assert_eq!(ctx, FeatureContext(vec![-2.0, 0.0]));
+        assert_eq!(env.get_reward(&a0, &ctx), DummyReward(0.25));
+
+        // The replay wraps around, and clones restart from the first record.
+        assert_eq!(env.get_context(), FeatureContext(vec![0.5, 1.0]));
+        let cloned = env.clone();
+        assert_eq!(cloned.get_context(), FeatureContext(vec![0.5, 1.0]));
+    }
+
+    #[test]
+    fn test_replay_from_csv_malformed_rows() {
+        let path = write_temp_csv("replay_bad_reward.csv", "0.5,1,0.7\n0.1,0,high\n");
+        let err = load(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            err,
+            OctopusError::MalformedRow {
+                line: 2,
+                reason: "bad reward 'high'".to_string(),
+            }
+        );
+
+        let path = write_temp_csv("replay_missing_column.csv", "0.5,1,0.7\n1,0.3\n");
+        let err = load(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            err,
+            OctopusError::MalformedRow {
+                line: 2,
+                reason: "expected 'context,action,reward', found '1,0.3'".to_string(),
+            }
+        );
+    }
+}
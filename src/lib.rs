@@ -1,4 +1,6 @@
 pub mod algorithms;
+pub mod environments;
 pub mod simulation;
 pub mod traits;
 pub mod utils;
+pub mod wrappers;
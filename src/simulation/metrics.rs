@@ -1,35 +1,164 @@
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+
+use crate::utils::special::regularized_incomplete_beta;
+
+/// Floating-point type used to store the per-step trajectories of `SimulationResults`.
+///
+/// Implemented for `f64` (the default) and `f32`, which halves the memory of large simulations.
+pub trait StepValue: Copy + Debug + PartialEq + Send + Sync + 'static {
+    /// Converts from `f64`, rounding if the type is narrower.
+    fn from_f64(value: f64) -> Self;
+    /// Converts to `f64`.
+    fn to_f64(self) -> f64;
+}
+
+impl StepValue for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+impl StepValue for f32 {
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
 /// Stores the results of a single bandit simulation episode.
+///
+/// Cumulative totals are always `f64`; the per-step trajectories are stored as `T` (see `StepValue`).
 #[derive(Debug, Clone, PartialEq)] // Derive common traits for convenience
-pub struct SimulationResults {
+pub struct SimulationResults<T: StepValue = f64> {
     /// Total reward accumulated by the policy.
     pub cumulative_reward: f64,
     /// Total reward that would have been obtained by always choosing the optimal action.
     pub cumulative_optimal_reward: f64,
+    /// Total reward credited to the policy for regret: the true means of the chosen arms when the
+    /// environment exposes them, and otherwise the observed rewards (i.e. `cumulative_reward`).
+    pub cumulative_realized_reward: f64,
     /// Reward received at each step.
-    pub steps_rewards: Vec<f64>,
+    pub steps_rewards: Vec<T>,
     /// Cumulative regret at each step.
-    pub steps_regret: Vec<f64>,
+    pub steps_regret: Vec<T>,
+    /// ID of the action chosen at each step; empty unless the episode was traced.
+    pub chosen_action_ids: Vec<u32>,
+    /// Probability with which the chosen action was selected at each step, as reported by
+    /// `BanditPolicy::selection_probability`; `NaN` where the policy could not report it.
+    pub chosen_propensities: Vec<T>,
 }
 
-impl SimulationResults {
-    /// Creates a new SimulationResults instance.
+impl<T: StepValue> SimulationResults<T> {
+    /// Creates a new SimulationResults instance, crediting the policy with its observed rewards.
     pub fn new(
         cumulative_reward: f64,
         cumulative_optimal_reward: f64,
-        steps_rewards: Vec<f64>,
-        steps_regret: Vec<f64>,
+        steps_rewards: Vec<T>,
+        steps_regret: Vec<T>,
     ) -> Self {
         SimulationResults {
             cumulative_reward,
             cumulative_optimal_reward,
+            cumulative_realized_reward: cumulative_reward,
             steps_rewards,
             steps_regret,
+            chosen_action_ids: Vec::new(),
+            chosen_propensities: Vec::new(),
         }
     }
 
+    /// Sets the total reward credited to the policy for regret, e.g. the sum of the chosen arms'
+    /// true means, so that cumulative regret matches the last `steps_regret` entry.
+    pub fn with_cumulative_realized_reward(mut self, cumulative_realized_reward: f64) -> Self {
+        self.cumulative_realized_reward = cumulative_realized_reward;
+        self
+    }
+
+    /// Attaches the per-step chosen action IDs, as recorded by `Simulator::run_episode_traced`.
+    pub fn with_chosen_action_ids(mut self, chosen_action_ids: Vec<u32>) -> Self {
+        self.chosen_action_ids = chosen_action_ids;
+        self
+    }
+
+    /// Attaches the per-step propensities of the chosen actions, as recorded by `Simulator`.
+    pub fn with_chosen_propensities(mut self, chosen_propensities: Vec<T>) -> Self {
+        self.chosen_propensities = chosen_propensities;
+        self
+    }
+
+    /// Returns the number of steps whose chosen action had a propensity below `threshold`.
+    ///
+    /// A low propensity means the action was unlikely to be picked, i.e. the step was exploratory.
+    /// Steps with an unknown (`NaN`) propensity are not counted.
+    pub fn exploration_count(&self, threshold: f64) -> usize {
+        self.chosen_propensities
+            .iter()
+            .filter(|propensity| propensity.to_f64() < threshold)
+            .count()
+    }
+
     /// Returns the final simple regret (difference from optimal at the last step).
     pub fn final_simple_regret(&self) -> f64 {
-        self.cumulative_optimal_reward - self.cumulative_reward
+        self.cumulative_optimal_reward - self.cumulative_realized_reward
+    }
+
+    /// Returns the cumulative regret after the given (0-based) step, or `None` if out of range.
+    pub fn regret_at(&self, step: usize) -> Option<f64> {
+        self.steps_regret.get(step).map(|regret| regret.to_f64())
+    }
+
+    /// Returns the reward received at the given (0-based) step, or `None` if out of range.
+    pub fn reward_at(&self, step: usize) -> Option<f64> {
+        self.steps_rewards.get(step).map(|reward| reward.to_f64())
+    }
+
+    /// Returns the step at which the policy converged, or `None` if it did not.
+    ///
+    /// After each step, the most-pulled arm so far is determined (ties broken by smallest ID).
+    /// The policy converged at the step where this arm last changed, provided it then stayed the
+    /// same for at least `window` consecutive steps until the end of the episode.
+    /// Requires `chosen_action_ids` to be recorded; returns `None` if they are empty.
+    ///
+    /// Panics if `window` is 0.
+    pub fn convergence_step(&self, window: usize) -> Option<usize> {
+        assert!(window > 0, "Window must contain at least one step");
+        let mut pulls: HashMap<u32, usize> = HashMap::new();
+        let mut leader: Option<(usize, u32)> = None;
+        let mut last_change = 0;
+
+        for (step, &action_id) in self.chosen_action_ids.iter().enumerate() {
+            let count = pulls.entry(action_id).or_insert(0);
+            *count += 1;
+            let candidate = (*count, action_id);
+            let new_leader = match leader {
+                Some((leader_count, leader_id)) if leader_id == action_id => {
+                    Some((leader_count + 1, leader_id))
+                }
+                Some((leader_count, leader_id))
+                    if leader_count > candidate.0
+                        || (leader_count == candidate.0 && leader_id < action_id) =>
+                {
+                    Some((leader_count, leader_id))
+                }
+                _ => Some(candidate),
+            };
+            if new_leader.map(|(_, id)| id) != leader.map(|(_, id)| id) {
+                last_change = step;
+            }
+            leader = new_leader;
+        }
+
+        let num_steps = self.chosen_action_ids.len();
+        (num_steps > 0 && num_steps - last_change >= window).then_some(last_change)
     }
 }
 
@@ -42,48 +171,107 @@ pub struct SummaryStats {
     pub std_final_simple_regret: f64,
     pub average_step_rewards: Vec<f64>,
     pub average_step_regrets: Vec<f64>,
+    pub num_steps: usize,
 }
 
-pub fn analyze_results(results: &[SimulationResults]) -> SummaryStats {
-    let num_episodes = results.len();
-    assert!(num_episodes > 0, "Must have at least one simulation result");
+impl SummaryStats {
+    /// Returns the average per-step regret, `average_cumulative_regret / num_steps`.
+    ///
+    /// Unlike cumulative regret, this is comparable across experiments with different horizons.
+    /// Pass the recorded `self.num_steps` unless the regret should be spread over another horizon.
+    pub fn normalized_regret(&self, num_steps: usize) -> f64 {
+        self.average_cumulative_regret / num_steps as f64
+    }
 
-    let num_steps = results[0].steps_rewards.len();
-    let mut sum_cumulative_reward = 0.0;
-    let mut sum_cumulative_regret = 0.0;
-    let mut final_simple_regrets = Vec::with_capacity(num_episodes);
+    /// Prints the `Display` report to stdout.
+    pub fn print_report(&self) {
+        println!("{}", self);
+    }
+}
 
-    let mut step_rewards = vec![0.0; num_steps];
-    let mut step_regrets = vec![0.0; num_steps];
+/// Formats the scalar metrics as an aligned table, leaving out the per-step vectors.
+///
+/// The confidence interval of the final simple regret uses the normal approximation
+/// `mean ± 1.96 * std / sqrt(episodes)`.
+impl fmt::Display for SummaryStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let num_episodes = self.final_simple_regrets.len();
+        let ci_half_width = 1.96 * self.std_final_simple_regret / (num_episodes as f64).sqrt();
+        writeln!(f, "{:<30}{}", "Episodes", num_episodes)?;
+        writeln!(f, "{:<30}{}", "Steps", self.num_steps)?;
+        writeln!(
+            f,
+            "{:<30}{:.3}",
+            "Average cumulative reward", self.average_cumulative_reward
+        )?;
+        writeln!(
+            f,
+            "{:<30}{:.3}",
+            "Average cumulative regret", self.average_cumulative_regret
+        )?;
+        writeln!(
+            f,
+            "{:<30}{:.3} ± {:.3}",
+            "Final simple regret (± std)",
+            self.mean_final_simple_regret,
+            self.std_final_simple_regret
+        )?;
+        write!(
+            f,
+            "{:<30}[{:.3}, {:.3}]",
+            "Final simple regret 95% CI",
+            self.mean_final_simple_regret - ci_half_width,
+            self.mean_final_simple_regret + ci_half_width
+        )
+    }
+}
 
-    for res in results {
-        sum_cumulative_reward += res.cumulative_reward;
-        sum_cumulative_regret += res.cumulative_optimal_reward - res.cumulative_reward;
+/// Summarizes episodes of equal length, averaging rewards and regrets per step and overall.
+///
+/// Panics if results are empty or have different lengths, as `run_for_duration` episodes may.
+pub fn analyze_results<T: StepValue>(results: &[SimulationResults<T>]) -> SummaryStats {
+    let num_episodes = results.len();
+    assert!(num_episodes > 0, "Must have at least one simulation result");
 
-        let final_regret = res.final_simple_regret();
-        final_simple_regrets.push(final_regret);
+    let num_steps = results[0].steps_rewards.len();
+    assert!(
+        results.iter().all(|res| res.steps_rewards.len() == num_steps),
+        "All results must have the same number of steps"
+    );
 
-        for t in 0..num_steps {
-            step_rewards[t] += res.steps_rewards[t];
-            step_regrets[t] += res.steps_regret[t];
-        }
-    }
+    let cumulative_rewards: Vec<f64> = results.iter().map(|res| res.cumulative_reward).collect();
+    let cumulative_regrets: Vec<f64> = results
+        .iter()
+        .map(|res| res.cumulative_optimal_reward - res.cumulative_realized_reward)
+        .collect();
+    let final_simple_regrets: Vec<f64> =
+        results.iter().map(|res| res.final_simple_regret()).collect();
 
-    let average_cumulative_reward = sum_cumulative_reward / num_episodes as f64;
-    let average_cumulative_regret = sum_cumulative_regret / num_episodes as f64;
+    // Per-step accumulators use pairwise summation across episodes, so results do not depend
+    // on the episode order or on how rayon splits the work.
+    let (step_rewards, step_regrets): (Vec<f64>, Vec<f64>) = (0..num_steps)
+        .into_par_iter()
+        .map(|t| {
+            let rewards: Vec<f64> =
+                results.iter().map(|res| res.steps_rewards[t].to_f64()).collect();
+            let regrets: Vec<f64> =
+                results.iter().map(|res| res.steps_regret[t].to_f64()).collect();
+            (
+                pairwise_sum(&rewards) / num_episodes as f64,
+                pairwise_sum(&regrets) / num_episodes as f64,
+            )
+        })
+        .unzip();
 
-    for t in 0..num_steps {
-        step_rewards[t] /= num_episodes as f64;
-        step_regrets[t] /= num_episodes as f64;
-    }
+    let average_cumulative_reward = pairwise_sum(&cumulative_rewards) / num_episodes as f64;
+    let average_cumulative_regret = pairwise_sum(&cumulative_regrets) / num_episodes as f64;
 
-    let mean_final_simple_regret = final_simple_regrets.iter().sum::<f64>() / num_episodes as f64;
-    let std_final_simple_regret = (final_simple_regrets
+    let mean_final_simple_regret = pairwise_sum(&final_simple_regrets) / num_episodes as f64;
+    let squared_deviations: Vec<f64> = final_simple_regrets
         .iter()
         .map(|r| (r - mean_final_simple_regret).powi(2))
-        .sum::<f64>()
-        / num_episodes as f64)
-        .sqrt();
+        .collect();
+    let std_final_simple_regret = (pairwise_sum(&squared_deviations) / num_episodes as f64).sqrt();
 
     SummaryStats {
         average_cumulative_reward,
@@ -93,5 +281,610 @@ pub fn analyze_results(results: &[SimulationResults]) -> SummaryStats {
         std_final_simple_regret,
         average_step_rewards: step_rewards,
         average_step_regrets: step_regrets,
+        num_steps,
+    }
+}
+
+/// Incrementally accumulates simulation results into summary statistics.
+///
+/// Produces the same `SummaryStats` as `analyze_results` without holding every
+/// `SimulationResults` in memory: running means and the variance of the final simple regret are
+/// updated online with Welford's algorithm as each result is pushed. Only the per-step averages
+/// and the final simple regret of each episode are kept.
+#[derive(Debug, Clone, Default)]
+pub struct StreamingSummary {
+    count: usize,
+    mean_cumulative_reward: f64,
+    mean_cumulative_regret: f64,
+    mean_final_simple_regret: f64,
+    m2_final_simple_regret: f64,
+    final_simple_regrets: Vec<f64>,
+    average_step_rewards: Vec<f64>,
+    average_step_regrets: Vec<f64>,
+}
+
+impl StreamingSummary {
+    /// Creates an empty summary.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of results pushed so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Folds one simulation result into the running statistics.
+    ///
+    /// Panics if the result has a different number of steps than the ones pushed before.
+    pub fn push<T: StepValue>(&mut self, results: &SimulationResults<T>) {
+        if self.count == 0 {
+            self.average_step_rewards = vec![0.0; results.steps_rewards.len()];
+            self.average_step_regrets = vec![0.0; results.steps_regret.len()];
+        }
+        assert!(
+            results.steps_rewards.len() == self.average_step_rewards.len()
+                && results.steps_regret.len() == self.average_step_regrets.len(),
+            "All simulation results must have the same number of steps"
+        );
+
+        self.count += 1;
+        let n = self.count as f64;
+
+        self.mean_cumulative_reward +=
+            (results.cumulative_reward - self.mean_cumulative_reward) / n;
+        let cumulative_regret =
+            results.cumulative_optimal_reward - results.cumulative_realized_reward;
+        self.mean_cumulative_regret += (cumulative_regret - self.mean_cumulative_regret) / n;
+
+        let final_simple_regret = results.final_simple_regret();
+        let delta = final_simple_regret - self.mean_final_simple_regret;
+        self.mean_final_simple_regret += delta / n;
+        self.m2_final_simple_regret +=
+            delta * (final_simple_regret - self.mean_final_simple_regret);
+        self.final_simple_regrets.push(final_simple_regret);
+
+        for (mean, value) in self.average_step_rewards.iter_mut().zip(&results.steps_rewards) {
+            *mean += (value.to_f64() - *mean) / n;
+        }
+        for (mean, value) in self.average_step_regrets.iter_mut().zip(&results.steps_regret) {
+            *mean += (value.to_f64() - *mean) / n;
+        }
+    }
+
+    /// Returns the accumulated statistics, with the population standard deviation as in `analyze_results`.
+    ///
+    /// Panics if no result has been pushed.
+    pub fn finalize(self) -> SummaryStats {
+        assert!(self.count > 0, "Must have at least one simulation result");
+        let num_steps = self.average_step_rewards.len();
+        SummaryStats {
+            average_cumulative_reward: self.mean_cumulative_reward,
+            average_cumulative_regret: self.mean_cumulative_regret,
+            final_simple_regrets: self.final_simple_regrets,
+            mean_final_simple_regret: self.mean_final_simple_regret,
+            std_final_simple_regret: (self.m2_final_simple_regret / self.count as f64).sqrt(),
+            average_step_rewards: self.average_step_rewards,
+            average_step_regrets: self.average_step_regrets,
+            num_steps,
+        }
+    }
+}
+
+/// Sums values by recursive halving (pairwise summation).
+///
+/// The rounding error grows with `O(log n)` instead of `O(n)` for naive left-to-right summation,
+/// and the result only depends on the order of `values`.
+fn pairwise_sum(values: &[f64]) -> f64 {
+    const BLOCK_SIZE: usize = 8;
+    if values.len() <= BLOCK_SIZE {
+        return values.iter().sum();
+    }
+    let (left, right) = values.split_at(values.len() / 2);
+    pairwise_sum(left) + pairwise_sum(right)
+}
+
+/// Computes the average instantaneous regret over a trailing window, for each step.
+///
+/// Instantaneous regret is the per-step increase of `steps_regret`. For step `t`, the value is the mean
+/// instantaneous regret over steps `max(0, t + 1 - window)..=t`, averaged across all results.
+/// Useful to see how quickly a policy re-adapts after a distribution shift.
+pub fn windowed_regret<T: StepValue>(results: &[SimulationResults<T>], window: usize) -> Vec<f64> {
+    let num_episodes = results.len();
+    assert!(num_episodes > 0, "Must have at least one simulation result");
+    assert!(window > 0, "Window must contain at least one step");
+
+    let num_steps = results[0].steps_regret.len();
+    let mut windowed = vec![0.0; num_steps];
+
+    for res in results {
+        for (t, value) in windowed.iter_mut().enumerate() {
+            let start = (t + 1).saturating_sub(window);
+            let regret_before_window = if start == 0 {
+                0.0
+            } else {
+                res.steps_regret[start - 1].to_f64()
+            };
+            let steps_in_window = (t + 1 - start) as f64;
+            *value += (res.steps_regret[t].to_f64() - regret_before_window) / steps_in_window;
+        }
+    }
+
+    for value in windowed.iter_mut() {
+        *value /= num_episodes as f64;
+    }
+    windowed
+}
+
+/// Computes the Lai-Robbins asymptotic regret lower bound `sum(gap_i / kl_i) * ln(t)` for `t = 1..=steps`.
+///
+/// * `arm_gaps` - Gap between the optimal mean and each arm's mean. Optimal arms (gap 0.0) are skipped.
+/// * `kl_divergences` - KL divergence between each arm's reward distribution and the optimal one.
+/// * `steps` - Number of steps of the reference curve.
+///
+/// Intended as a reference curve to plot alongside empirical cumulative regret.
+pub fn theoretical_regret_bound(
+    arm_gaps: &[f64],
+    kl_divergences: &[f64],
+    steps: usize,
+) -> Vec<f64> {
+    assert_eq!(
+        arm_gaps.len(),
+        kl_divergences.len(),
+        "Must provide one KL divergence per arm gap"
+    );
+
+    let coefficient: f64 = arm_gaps
+        .iter()
+        .zip(kl_divergences)
+        .filter(|&(&gap, _)| gap > 0.0)
+        .map(|(&gap, &kl)| {
+            assert!(
+                kl > 0.0,
+                "KL divergence of a suboptimal arm must be positive"
+            );
+            gap / kl
+        })
+        .sum();
+
+    (1..=steps).map(|t| coefficient * (t as f64).ln()).collect()
+}
+
+/// Per-step cumulative regret with a 95% normal confidence band, ready to plot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegretPlotData {
+    /// Step indices, matching the indices of `SimulationResults::steps_regret`.
+    pub steps: Vec<usize>,
+    /// Mean cumulative regret across runs at each step.
+    pub mean_regret: Vec<f64>,
+    /// `mean - 1.96 * std` at each step.
+    pub lower_band: Vec<f64>,
+    /// `mean + 1.96 * std` at each step.
+    pub upper_band: Vec<f64>,
+}
+
+/// Computes the mean cumulative regret per step with a band of ±1.96 standard deviations across runs.
+///
+/// Uses the population standard deviation, as `analyze_results` does for final regrets.
+/// Panics if results are empty or have different lengths.
+pub fn regret_plot_data<T: StepValue>(results: &[SimulationResults<T>]) -> RegretPlotData {
+    assert!(
+        !results.is_empty(),
+        "Must have at least one simulation result"
+    );
+    let num_steps = results[0].steps_regret.len();
+    assert!(
+        results.iter().all(|res| res.steps_regret.len() == num_steps),
+        "All results must have the same number of steps"
+    );
+    let num_episodes = results.len() as f64;
+
+    let (mean_regret, std_regret): (Vec<f64>, Vec<f64>) = (0..num_steps)
+        .into_par_iter()
+        .map(|t| {
+            let regrets: Vec<f64> =
+                results.iter().map(|res| res.steps_regret[t].to_f64()).collect();
+            let mean = pairwise_sum(&regrets) / num_episodes;
+            let squared_deviations: Vec<f64> = regrets.iter().map(|r| (r - mean).powi(2)).collect();
+            (
+                mean,
+                (pairwise_sum(&squared_deviations) / num_episodes).sqrt(),
+            )
+        })
+        .unzip();
+
+    RegretPlotData {
+        steps: (0..num_steps).collect(),
+        lower_band: mean_regret
+            .iter()
+            .zip(&std_regret)
+            .map(|(mean, std)| mean - 1.96 * std)
+            .collect(),
+        upper_band: mean_regret
+            .iter()
+            .zip(&std_regret)
+            .map(|(mean, std)| mean + 1.96 * std)
+            .collect(),
+        mean_regret,
+    }
+}
+
+/// Compares the final simple regrets of two result sets with Welch's unequal-variance t-test.
+///
+/// Returns `(t_statistic, p_value)`, where the t-statistic is positive when `a` has the higher mean
+/// final regret and the two-sided p-value uses the Welch-Satterthwaite degrees of freedom.
+/// If both sets have zero variance, the p-value is 1.0 for equal means and 0.0 otherwise.
+///
+/// Panics if either result set has fewer than two results.
+pub fn welch_t_test<T: StepValue>(
+    a: &[SimulationResults<T>],
+    b: &[SimulationResults<T>],
+) -> (f64, f64) {
+    assert!(
+        a.len() >= 2 && b.len() >= 2,
+        "Each result set must have at least two simulation results"
+    );
+
+    let sample_stats = |results: &[SimulationResults<T>]| {
+        let regrets: Vec<f64> = results.iter().map(|res| res.final_simple_regret()).collect();
+        let n = regrets.len() as f64;
+        let mean = pairwise_sum(&regrets) / n;
+        let squared_deviations: Vec<f64> = regrets.iter().map(|r| (r - mean).powi(2)).collect();
+        let variance = pairwise_sum(&squared_deviations) / (n - 1.0);
+        (mean, variance / n)
+    };
+    let (mean_a, var_mean_a) = sample_stats(a);
+    let (mean_b, var_mean_b) = sample_stats(b);
+
+    let standard_error_sq = var_mean_a + var_mean_b;
+    if standard_error_sq == 0.0 {
+        return if mean_a == mean_b {
+            (0.0, 1.0)
+        } else {
+            ((mean_a - mean_b).signum() * f64::INFINITY, 0.0)
+        };
+    }
+
+    let t_statistic = (mean_a - mean_b) / standard_error_sq.sqrt();
+    let degrees_of_freedom = standard_error_sq.powi(2)
+        / (var_mean_a.powi(2) / (a.len() as f64 - 1.0)
+            + var_mean_b.powi(2) / (b.len() as f64 - 1.0));
+    (
+        t_statistic,
+        student_t_two_sided_p(t_statistic, degrees_of_freedom),
+    )
+}
+
+/// Two-sided p-value `P(|T| >= |t|)` of Student's t distribution with `df` degrees of freedom.
+fn student_t_two_sided_p(t: f64, df: f64) -> f64 {
+    regularized_incomplete_beta(df / (df + t * t), df / 2.0, 0.5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::epsilon_greedy::EpsilonGreedyPolicy;
+    use crate::simulation::simulator::Simulator;
+    use crate::traits::entities::{Action, DummyContext, NumericAction, Reward};
+    use crate::traits::environment::Environment;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    /// Environment whose best arm switches from id 0 to id 1 after `shift_step` steps.
+    #[derive(Debug, Clone)]
+    struct DriftingEnvironment {
+        step: Arc<AtomicUsize>,
+        shift_step: usize,
+    }
+
+    impl Environment<NumericAction<i32>, DummyReward, DummyContext> for DriftingEnvironment {
+        fn get_context(&self) -> DummyContext {
+            self.step.fetch_add(1, Ordering::SeqCst);
+            DummyContext
+        }
+
+        fn get_reward(&self, action: &NumericAction<i32>, _context: &DummyContext) -> DummyReward {
+            let shifted = self.step.load(Ordering::SeqCst) > self.shift_step;
+            let best_id = if shifted { 1 } else { 0 };
+            DummyReward(if action.id() == best_id { 1.0 } else { 0.0 })
+        }
+    }
+
+    #[test]
+    fn test_windowed_regret_on_hand_computed_results() {
+        // Instantaneous regrets: 1, 0, 1, 1
+        let results = vec![SimulationResults::new(
+            1.0,
+            3.0,
+            vec![0.0, 1.0, 0.0, 0.0],
+            vec![1.0, 1.0, 2.0, 3.0],
+        )];
+        let windowed = windowed_regret(&results, 2);
+        assert_eq!(windowed, vec![1.0, 0.5, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_regret_plot_data_bands_bracket_mean() {
+        let results = vec![
+            SimulationResults::new(0.0, 3.0, vec![0.0; 3], vec![1.0, 2.0, 3.0]),
+            SimulationResults::new(0.0, 5.0, vec![0.0; 3], vec![1.0, 3.0, 5.0]),
+        ];
+        let plot = regret_plot_data(&results);
+
+        assert_eq!(plot.steps, vec![0, 1, 2]);
+        assert_eq!(plot.mean_regret, vec![1.0, 2.5, 4.0]);
+        // Population std per step: 0, 0.5, 1.
+        assert_eq!(plot.lower_band, vec![1.0, 2.5 - 0.98, 4.0 - 1.96]);
+        assert_eq!(plot.upper_band, vec![1.0, 2.5 + 0.98, 4.0 + 1.96]);
+        for t in plot.steps {
+            assert!(plot.lower_band[t] <= plot.mean_regret[t]);
+            assert!(plot.mean_regret[t] <= plot.upper_band[t]);
+            assert!(plot.upper_band[t] - plot.lower_band[t] >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_windowed_regret_drops_after_readaptation() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "early best"),
+            NumericAction::with_id(1, 1i32, "late best"),
+        ];
+        let policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.1, &actions,
+        )
+        .unwrap();
+        let env = DriftingEnvironment {
+            step: Arc::new(AtomicUsize::new(0)),
+            shift_step: 100,
+        };
+
+        let mut simulator = Simulator::new(policy, env);
+        let results = vec![simulator.run_episode(600, &actions)];
+        let windowed = windowed_regret(&results, 50);
+
+        let before_shift = windowed[99];
+        let right_after_shift = windowed[149];
+        let after_readaptation = windowed[599];
+        assert!(right_after_shift > before_shift);
+        assert!(after_readaptation < right_after_shift);
+        assert!(
+            after_readaptation < 0.2,
+            "windowed regret: {}",
+            after_readaptation
+        );
+    }
+
+    #[test]
+    fn test_theoretical_regret_bound_two_arms() {
+        // Optimal arm has gap 0.0; the other has gap 0.2 and KL 0.1, so the coefficient is 2.0.
+        let bound = theoretical_regret_bound(&[0.0, 0.2], &[0.0, 0.1], 4);
+        let expected = [0.0, 2.0 * 2f64.ln(), 2.0 * 3f64.ln(), 2.0 * 4f64.ln()];
+        assert_eq!(bound.len(), 4);
+        for (value, expected) in bound.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_pairwise_sum_beats_naive_sum() {
+        // Each tiny value is below half an ulp of 1.0, so naive summation loses all of them.
+        let mut values = vec![1.0];
+        values.extend(std::iter::repeat_n(1e-16, 1_000_000));
+        let reference = 1.0 + 1e-10;
+
+        let naive: f64 = values.iter().sum();
+        let pairwise = pairwise_sum(&values);
+        assert!((pairwise - reference).abs() < (naive - reference).abs());
+        assert!((pairwise - reference).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_analyze_results_averages() {
+        let results = vec![
+            SimulationResults::new(3.0, 4.0, vec![1.0, 2.0], vec![0.0, 1.0]),
+            SimulationResults::new(1.0, 4.0, vec![0.0, 1.0], vec![1.0, 3.0]),
+        ];
+        let stats = analyze_results(&results);
+        assert_eq!(stats.average_cumulative_reward, 2.0);
+        assert_eq!(stats.average_cumulative_regret, 2.0);
+        assert_eq!(stats.final_simple_regrets, vec![1.0, 3.0]);
+        assert_eq!(stats.mean_final_simple_regret, 2.0);
+        assert_eq!(stats.std_final_simple_regret, 1.0);
+        assert_eq!(stats.average_step_rewards, vec![0.5, 1.5]);
+        assert_eq!(stats.average_step_regrets, vec![0.5, 2.0]);
+        assert_eq!(stats.num_steps, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "All results must have the same number of steps")]
+    fn test_analyze_results_rejects_unequal_lengths() {
+        let results = vec![
+            SimulationResults::new(3.0, 4.0, vec![1.0, 2.0], vec![0.0, 1.0]),
+            SimulationResults::new(1.0, 2.0, vec![1.0], vec![1.0]),
+        ];
+        analyze_results(&results);
+    }
+
+    #[test]
+    fn test_normalized_regret_is_mean_per_step_regret() {
+        // Per-step regrets [1, 0, 1, 0] and [2, 1, 0, 1]: cumulative regrets 2 and 4, so the
+        // average cumulative regret is 3 and the mean per-step regret 3 / 4.
+        let results = vec![
+            SimulationResults::new(2.0, 4.0, vec![0.0, 1.0, 0.0, 1.0], vec![1.0, 0.0, 1.0, 0.0]),
+            SimulationResults::new(4.0, 8.0, vec![0.0, 1.0, 2.0, 1.0], vec![2.0, 1.0, 0.0, 1.0]),
+        ];
+        let stats = analyze_results(&results);
+        assert_eq!(stats.num_steps, 4);
+        assert_eq!(stats.average_cumulative_regret, 3.0);
+        assert_eq!(stats.normalized_regret(stats.num_steps), 0.75);
+        let mean_step_regret = stats.average_step_regrets.iter().sum::<f64>() / 4.0;
+        assert_eq!(stats.normalized_regret(stats.num_steps), mean_step_regret);
+    }
+
+    #[test]
+    fn test_summary_stats_display_lists_scalar_metrics() {
+        let results = vec![
+            SimulationResults::new(3.0, 4.0, vec![1.0, 2.0], vec![0.0, 1.0]),
+            SimulationResults::new(1.0, 4.0, vec![0.0, 1.0], vec![1.0, 3.0]),
+        ];
+        let report = analyze_results(&results).to_string();
+
+        for expected in [
+            "Episodes                      2",
+            "Steps                         2",
+            "Average cumulative reward     2.000",
+            "Average cumulative regret     2.000",
+            "Final simple regret (± std)   2.000 ± 1.000",
+            "Final simple regret 95% CI    [0.614, 3.386]",
+        ] {
+            assert!(
+                report.contains(expected),
+                "missing {:?} in\n{}",
+                expected,
+                report
+            );
+        }
+        assert_eq!(report.lines().count(), 6);
+    }
+
+    #[test]
+    fn test_print_report_single_episode() {
+        let results = vec![SimulationResults::new(
+            3.0,
+            4.0,
+            vec![1.0, 2.0],
+            vec![0.0, 1.0],
+        )];
+        let stats = analyze_results(&results);
+        // `print_report` writes the `Display` output, which stays finite for a single episode.
+        assert!(!stats.to_string().contains("NaN"), "{}", stats);
+        stats.print_report();
+    }
+
+    #[test]
+    fn test_streaming_summary_matches_analyze_results() {
+        let results: Vec<SimulationResults> = (0..7)
+            .map(|i| {
+                let x = i as f64;
+                SimulationResults::new(
+                    10.0 + x * 1.5,
+                    20.0 + x * 0.25,
+                    vec![x, 2.0 * x, 0.5 - x],
+                    vec![0.1 * x * x, 1.0 + x, 3.0 - 0.3 * x],
+                )
+            })
+            .collect();
+
+        let mut streaming = StreamingSummary::new();
+        for res in &results {
+            streaming.push(res);
+        }
+        assert_eq!(streaming.count(), results.len());
+        let streamed = streaming.finalize();
+        let batch = analyze_results(&results);
+
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+        assert!(close(
+            streamed.average_cumulative_reward,
+            batch.average_cumulative_reward
+        ));
+        assert!(close(
+            streamed.average_cumulative_regret,
+            batch.average_cumulative_regret
+        ));
+        assert_eq!(streamed.final_simple_regrets, batch.final_simple_regrets);
+        assert!(close(
+            streamed.mean_final_simple_regret,
+            batch.mean_final_simple_regret
+        ));
+        assert!(close(
+            streamed.std_final_simple_regret,
+            batch.std_final_simple_regret
+        ));
+        for (a, b) in streamed.average_step_rewards.iter().zip(&batch.average_step_rewards) {
+            assert!(close(*a, *b));
+        }
+        for (a, b) in streamed.average_step_regrets.iter().zip(&batch.average_step_regrets) {
+            assert!(close(*a, *b));
+        }
+    }
+
+    #[test]
+    fn test_regret_and_reward_at() {
+        let results = SimulationResults::new(3.0, 6.0, vec![1.0, 2.0], vec![1.0, 3.0]);
+        assert_eq!(results.reward_at(0), Some(1.0));
+        assert_eq!(results.reward_at(1), Some(2.0));
+        assert_eq!(results.regret_at(1), Some(3.0));
+        assert_eq!(results.reward_at(2), None);
+        assert_eq!(results.regret_at(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_student_t_p_value_reference_points() {
+        // Cauchy (df = 1): P(|T| >= 1) = 0.5.
+        assert!((student_t_two_sided_p(1.0, 1.0) - 0.5).abs() < 1e-10);
+        // Two-sided 5% critical value for df = 10.
+        assert!((student_t_two_sided_p(2.228_138_851_986_274, 10.0) - 0.05).abs() < 1e-9);
+        assert!((student_t_two_sided_p(0.0, 5.0) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_welch_t_test() {
+        let with_regrets = |regrets: &[f64]| -> Vec<SimulationResults> {
+            regrets
+                .iter()
+                .map(|&regret| SimulationResults::new(100.0 - regret, 100.0, vec![0.0], vec![0.0]))
+                .collect()
+        };
+        let low: Vec<f64> = (0..30).map(|i| 10.0 + (i % 5) as f64 * 0.5).collect();
+        let high: Vec<f64> = (0..40).map(|i| 20.0 + (i % 7) as f64 * 0.7).collect();
+
+        let (t, p) = welch_t_test(&with_regrets(&high), &with_regrets(&low));
+        assert!(t > 10.0, "t: {}", t);
+        assert!(p < 1e-6, "p: {}", p);
+
+        let (t, p) = welch_t_test(&with_regrets(&low), &with_regrets(&low));
+        assert!(t.abs() < 1e-12);
+        assert!(p > 0.99, "p: {}", p);
+    }
+
+    #[test]
+    fn test_convergence_step() {
+        let traced = |ids: Vec<u32>| {
+            SimulationResults::<f64>::new(0.0, 0.0, vec![], vec![]).with_chosen_action_ids(ids)
+        };
+
+        // Arm 0 leads on ties; arm 1 takes the lead for good at step 4.
+        let results = traced(vec![0, 1, 0, 1, 1, 1, 1, 1]);
+        assert_eq!(results.convergence_step(4), Some(4));
+        assert_eq!(results.convergence_step(5), None);
+        assert_eq!(traced(vec![]).convergence_step(1), None);
+
+        // A clear two-arm problem: epsilon-greedy locks onto the better arm almost immediately.
+        let actions = vec![
+            NumericAction::with_id(0, 0, "bad"),
+            NumericAction::with_id(1, 1, "good"),
+        ];
+        let policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.1, &actions,
+        )
+        .unwrap();
+        // Shifted from the first step on, so arm 1 pays 1.0 and arm 0 pays 0.0 throughout.
+        let env = DriftingEnvironment {
+            step: Arc::new(AtomicUsize::new(0)),
+            shift_step: 0,
+        };
+        let results = Simulator::new(policy, env).run_episode(1000, &actions);
+        let converged_at = results.convergence_step(500).expect("policy should converge");
+        assert!(converged_at < 50, "converged at step {}", converged_at);
     }
 }
@@ -1,11 +1,42 @@
-use crate::simulation::metrics::SimulationResults;
+use crate::simulation::metrics::{SimulationResults, StepValue};
 use crate::traits::entities::{Action, Context, Reward};
-use crate::traits::environment::Environment;
+use crate::traits::environment::{Environment, KnownMeansEnvironment};
 use crate::traits::policy::BanditPolicy;
+use crate::utils::error::OctopusError;
+use crate::utils::reward_transform::RewardTransform;
 
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 use rayon::prelude::*;
 
+use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Number of steps between two wall-clock checks in `Simulator::run_for_duration`.
+const DEADLINE_CHECK_INTERVAL: usize = 64;
+
+/// Offset between the policy and environment seeds of a run in `run_parallel_simulations_progress`,
+/// so the two never share a random stream.
+const ENVIRONMENT_SEED_OFFSET: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// The splitmix64 finalizer, a bijective mixer spreading every input bit over the output.
+fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Returns the policy seed of run `run_index` in `run_parallel_simulations_progress`.
+///
+/// Mixing `base_seed` before combining it with the index keeps nearby base seeds from sharing
+/// runs, e.g. `(0, 1)` and `(1, 0)` get unrelated seeds.
+fn run_seed(base_seed: u64, run_index: u64) -> u64 {
+    splitmix64(splitmix64(base_seed) ^ run_index)
+}
 
 /// Simulator for running Multi-Armed Bandit experiments.
 ///
@@ -43,46 +74,429 @@ where
         }
     }
 
-    /// Runs a simulation episode for a given number of steps..clone()
+    /// Runs a simulation episode for a given number of steps.
     ///
     /// * `num_steps` - Number of time steps to simulate.
     /// * `all_actions` - Slice of all possible actions (for regret calculation).
     ///
-    /// Returns a SimulationResults object with cumulative rewards and regret.
+    /// Returns a SimulationResults object with cumulative rewards, regret and the chosen action IDs.
+    /// If the environment exposes its true arm means (see `Environment::known_means`), regret is the
+    /// exact expected regret: `cumulative_optimal_reward` sums the best mean at each step and
+    /// `cumulative_realized_reward` the mean of each chosen arm. Otherwise both come from the sampled
+    /// `get_optimal_reward` and the observed rewards.
     pub fn run_episode(&mut self, num_steps: usize, all_actions: &[A]) -> SimulationResults {
-        let mut cumulative_reward: f64 = 0.0;
-        let mut cumulative_optimal_reward: f64 = 0.0;
-        let mut steps_rewards: Vec<f64> = Vec::with_capacity(num_steps);
-        let mut steps_regret: Vec<f64> = Vec::with_capacity(num_steps);
+        self.run_episode_traced(num_steps, all_actions).0
+    }
+
+    /// Runs a simulation episode like `run_episode`, also recording the chosen action ID at each step.
+    ///
+    /// Returns the SimulationResults together with the per-step chosen action IDs, for replaying
+    /// and debugging specific decisions.
+    pub fn run_episode_traced(
+        &mut self,
+        num_steps: usize,
+        all_actions: &[A],
+    ) -> (SimulationResults, Vec<u32>) {
+        let results = self.run_episode_stored::<f64>(num_steps, all_actions);
+        let chosen_action_ids = results.chosen_action_ids.clone();
+        (results, chosen_action_ids)
+    }
+
+    /// Runs a simulation episode like `run_episode`, storing the per-step trajectories as `T`.
+    ///
+    /// Use `f32` to halve the memory of long episodes; cumulative totals are still accumulated in
+    /// `f64`, so only the stored per-step values are rounded.
+    pub fn run_episode_stored<T: StepValue>(
+        &mut self,
+        num_steps: usize,
+        all_actions: &[A],
+    ) -> SimulationResults<T> {
+        let mut recorder = EpisodeRecorder::with_capacity(num_steps);
+        for _step in 0..num_steps {
+            let (context, chosen_action, reward) = self.play_step(all_actions, &mut recorder);
+            self.policy.update(&context, &chosen_action, &reward);
+        }
+        recorder.finish()
+    }
+
+    /// Runs a simulation episode like `run_episode` for as many steps as fit in a wall-clock `budget`.
+    ///
+    /// The elapsed time is checked once every `DEADLINE_CHECK_INTERVAL` steps to keep the overhead
+    /// low, so the run may overshoot the budget by up to that many steps. Returns the results of
+    /// every completed step; a zero budget completes none. Runs rarely share a length, so truncate
+    /// them to a common one before passing several to `analyze_results`.
+    pub fn run_for_duration(&mut self, budget: Duration, all_actions: &[A]) -> SimulationResults {
+        let start = Instant::now();
+        let mut recorder = EpisodeRecorder::with_capacity(0);
+        for step in 0.. {
+            if step % DEADLINE_CHECK_INTERVAL == 0 && start.elapsed() >= budget {
+                break;
+            }
+            let (context, chosen_action, reward) = self.play_step(all_actions, &mut recorder);
+            self.policy.update(&context, &chosen_action, &reward);
+        }
+        recorder.finish()
+    }
+
+    /// Runs a simulation episode like `run_episode`, but measures regret against the best fixed arm
+    /// in hindsight instead of the per-step optimum.
+    ///
+    /// Every step records the expected reward of each arm: its true mean if the environment exposes
+    /// them, and otherwise `get_optimal_reward` over that arm alone. Once the episode ends, the arm
+    /// with the highest total is the fixed best arm, and `steps_regret` and
+    /// `cumulative_optimal_reward` compare the policy against always playing it. On a stationary
+    /// environment this matches `run_episode`; when the best arm changes over time it is lower.
+    ///
+    /// Requires an environment with `known_means` or a `get_optimal_reward` that does not sample.
+    /// The default `get_optimal_reward` draws one reward per arm from the environment's RNG, so the
+    /// policy would see a different reward stream than `run_episode` with the same seed.
+    pub fn run_episode_fixed_best(
+        &mut self,
+        num_steps: usize,
+        all_actions: &[A],
+    ) -> SimulationResults {
+        let mut recorder = EpisodeRecorder::with_capacity(num_steps);
+        let mut realized_rewards: Vec<f64> = Vec::with_capacity(num_steps);
+        let mut arm_values: Vec<Vec<f64>> = Vec::with_capacity(num_steps);
 
         for _step in 0..num_steps {
+            let (current_context, chosen_action, reward) = self.draw_step(&mut recorder);
+            self.policy.update(&current_context, &chosen_action, &reward);
+
+            let known = self.environment.known_means();
+            arm_values.push(
+                all_actions
+                    .iter()
+                    .map(|action| match known {
+                        Some(known) => known.true_mean(action),
+                        None => self
+                            .environment
+                            .get_optimal_reward(&current_context, std::slice::from_ref(action))
+                            .value(),
+                    })
+                    .collect(),
+            );
+            realized_rewards.push(match known {
+                Some(known) => known.true_mean(&chosen_action),
+                None => reward.value(),
+            });
+        }
+
+        // Smallest index on ties, as the arm totals are compared in `all_actions` order.
+        let best_index = (0..all_actions.len())
+            .map(|index| {
+                (
+                    index,
+                    arm_values.iter().map(|values| values[index]).sum::<f64>(),
+                )
+            })
+            .fold(
+                None,
+                |best: Option<(usize, f64)>, (index, total)| match best {
+                    Some((_, best_total)) if best_total >= total => best,
+                    _ => Some((index, total)),
+                },
+            )
+            .map_or(0, |(index, _)| index);
+
+        for (values, &realized) in arm_values.iter().zip(&realized_rewards) {
+            recorder.record_regret(values[best_index], realized);
+        }
+        recorder.finish()
+    }
+
+    /// Runs a simulation episode where each reward reaches the policy after a random delay.
+    ///
+    /// * `num_steps` - Number of time steps to simulate.
+    /// * `all_actions` - Slice of all possible actions (for regret calculation).
+    /// * `delay_sampler` - Draws the delay, in steps, of each reward; a delay of 0 updates the policy
+    ///   at the end of the same step.
+    /// * `seed` - Seed for the RNG passed to `delay_sampler`.
+    ///
+    /// Rewards and regret are recorded at the step the action is taken, as in `run_episode`. Pending rewards are applied
+    /// to the arm that earned them, in order of arrival, and any still pending at the end of the
+    /// episode are flushed, so every reward is eventually applied.
+    pub fn run_episode_random_delay(
+        &mut self,
+        num_steps: usize,
+        all_actions: &[A],
+        mut delay_sampler: impl FnMut(&mut StdRng) -> usize,
+        seed: u64,
+    ) -> SimulationResults {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut pending: Vec<(usize, C, A, R)> = Vec::new();
+        let mut recorder = EpisodeRecorder::with_capacity(num_steps);
+
+        for step in 0..num_steps {
+            let (current_context, chosen_action, reward) =
+                self.play_step(all_actions, &mut recorder);
+
+            let due_step = step + delay_sampler(&mut rng);
+            pending.push((due_step, current_context, chosen_action, reward));
+
+            let (due, waiting): (Vec<_>, Vec<_>) =
+                pending.into_iter().partition(|(due_step, ..)| *due_step <= step);
+            pending = waiting;
+            for (_, context, action, reward) in due {
+                self.policy.update(&context, &action, &reward);
+            }
+        }
+
+        // Flush rewards still in flight, earliest due first.
+        pending.sort_by_key(|(due_step, ..)| *due_step);
+        for (_, context, action, reward) in pending {
+            self.policy.update(&context, &action, &reward);
+        }
+
+        recorder.finish()
+    }
+
+    /// Runs a simulation episode like `run_episode`, but feeds the policy rewards normalized into `[0, 1]`.
+    ///
+    /// Each reward is mapped linearly from the environment's `reward_scale` range and clamped to
+    /// `[0, 1]`, which suits policies that assume bounded rewards (e.g. Thompson Sampling).
+    /// Rewards and regret in the returned SimulationResults stay on the environment's original scale.
+    ///
+    /// Panics if the environment's reward scale does not satisfy `min < max`.
+    pub fn run_episode_normalized(
+        &mut self,
+        num_steps: usize,
+        all_actions: &[A],
+    ) -> SimulationResults
+    where
+        R: From<f64>,
+    {
+        let (min, max) = self.environment.reward_scale();
+        assert!(min < max, "reward_scale must satisfy min < max");
+        self.run_episode_shaped(num_steps, all_actions, |raw| {
+            ((raw - min) / (max - min)).clamp(0.0, 1.0)
+        })
+    }
+
+    /// Runs a simulation episode like `run_episode`, but feeds the policy rewards shaped by `transform`.
+    ///
+    /// Rewards and regret in the returned SimulationResults stay on the environment's original scale.
+    pub fn run_episode_transformed(
+        &mut self,
+        num_steps: usize,
+        all_actions: &[A],
+        transform: &dyn RewardTransform,
+    ) -> SimulationResults
+    where
+        R: From<f64>,
+    {
+        self.run_episode_shaped(num_steps, all_actions, |raw| transform.transform(raw))
+    }
+
+    /// Shared loop of `run_episode_normalized` and `run_episode_transformed`: the policy learns
+    /// from `shape(reward)` while results are recorded on the original scale.
+    fn run_episode_shaped(
+        &mut self,
+        num_steps: usize,
+        all_actions: &[A],
+        shape: impl Fn(f64) -> f64,
+    ) -> SimulationResults
+    where
+        R: From<f64>,
+    {
+        let mut recorder = EpisodeRecorder::with_capacity(num_steps);
+        for _step in 0..num_steps {
+            let (context, chosen_action, reward) = self.play_step(all_actions, &mut recorder);
+            let shaped = shape(reward.value());
+            self.policy.update(&context, &chosen_action, &R::from(shaped));
+        }
+        recorder.finish()
+    }
+
+    /// Runs the policy until one arm is statistically identified as the best, or `max_steps` is reached.
+    ///
+    /// After every step, each pulled arm gets an anytime Hoeffding confidence interval of radius
+    /// `sqrt(ln(4 * K * t^2 / delta) / (2 * n))`, where `K` is the number of arms, `t` the current
+    /// step and `n` the arm's pull count. The run stops once every arm has been pulled and the
+    /// lower bound of the leading arm exceeds the upper bound of all others, which holds with
+    /// probability at least `1 - delta`. Rewards are assumed to lie in `[0, 1]`.
+    ///
+    /// Returns the identified arm and the number of steps used, or `None` if no arm was identified.
+    /// Returns `OctopusError::UnknownAction` if the policy chooses an action outside `all_actions`.
+    ///
+    /// Panics if `delta` is not in `(0, 1)`.
+    pub fn run_until_best_identified(
+        &mut self,
+        max_steps: usize,
+        all_actions: &[A],
+        delta: f64,
+    ) -> Result<Option<(A, usize)>, OctopusError> {
+        assert!(delta > 0.0 && delta < 1.0, "delta must be in (0, 1)");
+        let num_arms = all_actions.len() as f64;
+        let mut counts = vec![0u64; all_actions.len()];
+        let mut sum_rewards = vec![0.0; all_actions.len()];
+
+        for step in 1..=max_steps {
             let current_context = self.environment.get_context();
             let chosen_action = self.policy.choose_action(&current_context);
             let reward = self.environment.get_reward(&chosen_action, &current_context);
-
             self.policy.update(&current_context, &chosen_action, &reward);
-            cumulative_reward += reward.value();
 
-            // Regret calculation: difference between optimal and actual reward.
-            let optimal_reward_for_context =
-                self.environment.get_optimal_reward(&current_context, all_actions);
-            cumulative_optimal_reward += optimal_reward_for_context.value();
+            let index = all_actions.iter().position(|a| a.id() == chosen_action.id()).ok_or(
+                OctopusError::UnknownAction {
+                    action_id: chosen_action.id(),
+                },
+            )?;
+            counts[index] += 1;
+            sum_rewards[index] += reward.value();
+            if counts.contains(&0) {
+                continue;
+            }
+
+            let log_term = (4.0 * num_arms * (step as f64).powi(2) / delta).ln();
+            let bounds: Vec<(f64, f64)> = counts
+                .iter()
+                .zip(&sum_rewards)
+                .map(|(&count, &sum)| {
+                    let mean = sum / count as f64;
+                    let radius = (log_term / (2.0 * count as f64)).sqrt();
+                    (mean - radius, mean + radius)
+                })
+                .collect();
+            let leader = (0..all_actions.len())
+                .max_by(|&i, &j| {
+                    let mean_i = sum_rewards[i] / counts[i] as f64;
+                    let mean_j = sum_rewards[j] / counts[j] as f64;
+                    mean_i.total_cmp(&mean_j)
+                })
+                .unwrap();
+            let separated = bounds
+                .iter()
+                .enumerate()
+                .all(|(index, &(_, upper))| index == leader || bounds[leader].0 > upper);
+            if separated {
+                return Ok(Some((all_actions[leader].clone(), step)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Plays one step without updating the policy: serves a context, lets the policy choose, samples
+    /// the reward and records the choice and reward in `recorder`.
+    ///
+    /// Returns the step's context, chosen action and reward, leaving it to the caller to decide when
+    /// and how the policy learns from them.
+    fn draw_step<T: StepValue>(&self, recorder: &mut EpisodeRecorder<T>) -> (C, A, R) {
+        let context = self.environment.get_context();
+        let chosen_action = self.policy.choose_action(&context);
+        let propensity = self.chosen_propensity(&context, &chosen_action);
+        let reward = self.environment.get_reward(&chosen_action, &context);
+        recorder.record_choice(chosen_action.id(), propensity, reward.value());
+        (context, chosen_action, reward)
+    }
+
+    /// Plays one step like `draw_step`, also recording its regret against `all_actions`.
+    fn play_step<T: StepValue>(
+        &self,
+        all_actions: &[A],
+        recorder: &mut EpisodeRecorder<T>,
+    ) -> (C, A, R) {
+        let (context, chosen_action, reward) = self.draw_step(recorder);
+        let (optimal, realized) = self.regret_terms(&context, &chosen_action, &reward, all_actions);
+        recorder.record_regret(optimal, realized);
+        (context, chosen_action, reward)
+    }
+
+    /// Returns the policy's probability of having chosen `chosen`, or `NaN` if it cannot report one.
+    ///
+    /// Must be called before the policy is updated with the step's reward.
+    fn chosen_propensity(&self, context: &C, chosen: &A) -> f64 {
+        self.policy.selection_probability(context, chosen).unwrap_or(f64::NAN)
+    }
+
+    /// Returns the optimal and realized reward used for one step of regret accounting.
+    ///
+    /// Uses the environment's true means when it exposes them, and otherwise the sampled optimal
+    /// reward and the observed reward.
+    fn regret_terms(
+        &self,
+        context: &C,
+        chosen_action: &A,
+        reward: &R,
+        all_actions: &[A],
+    ) -> (f64, f64) {
+        match self.environment.known_means() {
+            Some(known) => {
+                let best_mean = all_actions
+                    .iter()
+                    .map(|action| known.true_mean(action))
+                    .fold(f64::NEG_INFINITY, f64::max);
+                (best_mean, known.true_mean(chosen_action))
+            }
+            None => (
+                self.environment.get_optimal_reward(context, all_actions).value(),
+                reward.value(),
+            ),
+        }
+    }
+}
 
-            let current_regret = cumulative_optimal_reward - cumulative_reward;
+/// Per-step trajectories and running totals of an episode in progress, shared by the `Simulator`
+/// episode loops.
+struct EpisodeRecorder<T: StepValue> {
+    chosen_action_ids: Vec<u32>,
+    chosen_propensities: Vec<T>,
+    cumulative_reward: f64,
+    cumulative_optimal_reward: f64,
+    cumulative_realized_reward: f64,
+    steps_rewards: Vec<T>,
+    steps_regret: Vec<T>,
+}
 
-            steps_rewards.push(reward.value());
-            steps_regret.push(current_regret);
+impl<T: StepValue> EpisodeRecorder<T> {
+    /// Creates an empty recorder with room for `num_steps` steps.
+    fn with_capacity(num_steps: usize) -> Self {
+        EpisodeRecorder {
+            chosen_action_ids: Vec::with_capacity(num_steps),
+            chosen_propensities: Vec::with_capacity(num_steps),
+            cumulative_reward: 0.0,
+            cumulative_optimal_reward: 0.0,
+            cumulative_realized_reward: 0.0,
+            steps_rewards: Vec::with_capacity(num_steps),
+            steps_regret: Vec::with_capacity(num_steps),
         }
+    }
+
+    /// Records the chosen action, its propensity and the observed reward of one step.
+    fn record_choice(&mut self, action_id: u32, propensity: f64, reward: f64) {
+        self.chosen_action_ids.push(action_id);
+        self.chosen_propensities.push(T::from_f64(propensity));
+        self.cumulative_reward += reward;
+        self.steps_rewards.push(T::from_f64(reward));
+    }
 
+    /// Adds one step's optimal and realized reward and records the cumulative regret so far.
+    fn record_regret(&mut self, optimal: f64, realized: f64) {
+        self.cumulative_optimal_reward += optimal;
+        self.cumulative_realized_reward += realized;
+        self.steps_regret.push(T::from_f64(
+            self.cumulative_optimal_reward - self.cumulative_realized_reward,
+        ));
+    }
+
+    /// Returns the recorded episode.
+    fn finish(self) -> SimulationResults<T> {
         SimulationResults::new(
-            cumulative_reward,
-            cumulative_optimal_reward,
-            steps_rewards,
-            steps_regret,
+            self.cumulative_reward,
+            self.cumulative_optimal_reward,
+            self.steps_rewards,
+            self.steps_regret,
         )
+        .with_cumulative_realized_reward(self.cumulative_realized_reward)
+        .with_chosen_action_ids(self.chosen_action_ids)
+        .with_chosen_propensities(self.chosen_propensities)
     }
 }
 
+/// Runs `num_runs` independent episodes in parallel, each starting from a clone of the policy and environment.
+///
+/// Clones keep the seeds configured by the caller; use `run_parallel_simulations_progress` for
+/// per-run derived seeds. Returns one SimulationResults per run, in run order.
 pub fn run_parallel_simulations<P, A, R, C, E>(
     policy: P,
     environment: E,
@@ -106,12 +520,223 @@ where
         .collect()
 }
 
+/// Same as `run_parallel_simulations`, but with a chosen `base_seed` and a `progress` counter
+/// incremented each time a run completes.
+///
+/// Run `i` reseeds its policy clone with a seed mixed from `base_seed` and `i` (see `run_seed`)
+/// and its environment clone with a seed derived from it (see `BanditPolicy::reseed` and `Environment::reseed`), so runs draw different
+/// random streams while repeating the call with the same `base_seed` reproduces every run.
+/// A caller thread can poll the shared counter to display progress; it reaches `num_runs`
+/// by the time this function returns.
+pub fn run_parallel_simulations_progress<P, A, R, C, E>(
+    policy: P,
+    environment: E,
+    all_actions: &[A],
+    num_steps: usize,
+    num_runs: usize,
+    base_seed: u64,
+    progress: Arc<AtomicUsize>,
+) -> Vec<SimulationResults>
+where
+    P: BanditPolicy<A, R, C> + Clone + Send + Sync + 'static,
+    E: Environment<A, R, C> + Clone + Send + Sync + 'static,
+    A: Action + Clone + Send + Sync + 'static,
+    R: Reward + Send + Sync + 'static,
+    C: Context + Send + Sync + 'static,
+{
+    (0..num_runs)
+        .into_par_iter()
+        .map(|run_index| {
+            let run_seed = run_seed(base_seed, run_index as u64);
+            let mut run_policy = policy.clone();
+            run_policy.reseed(run_seed);
+            let mut run_environment = environment.clone();
+            run_environment.reseed(run_seed.wrapping_add(ENVIRONMENT_SEED_OFFSET));
+            let mut sim = Simulator::new(run_policy, run_environment);
+            let results = sim.run_episode(num_steps, all_actions);
+            progress.fetch_add(1, Ordering::SeqCst);
+            results
+        })
+        .collect()
+}
+
+/// Runs every policy on the same pre-sampled reward tables, using common random numbers across policies.
+///
+/// For each of `num_blocks` blocks, `num_steps` contexts and the reward of every action in each context are
+/// drawn up front from `environment`, advancing its RNG so that blocks differ. Every policy then runs one
+/// episode per block against that block's table: two policies that choose the same action at the same step
+/// observe the same reward, so differences between their results come only from their choices. This reduces
+/// the variance of policy comparisons. Regret uses the environment's true means if it exposes them, and
+/// otherwise the best reward in the table at each step.
+///
+/// Returns `results[policy_index][block_index]`. Episodes run in parallel, each from `policy.fresh()`:
+/// learned state is discarded and the RNG restarts from the policy's seed. Policies whose `Clone` draws
+/// a new seed must override `fresh` to keep it, as `ThompsonSamplingPolicy` does, or repeated runs of
+/// the same policy will not be identical. Panics if a policy chooses an action outside `all_actions`.
+pub fn run_parallel_simulations_paired<P, A, R, C, E>(
+    policies: Vec<P>,
+    environment: E,
+    all_actions: &[A],
+    num_steps: usize,
+    num_blocks: usize,
+) -> Vec<Vec<SimulationResults>>
+where
+    P: BanditPolicy<A, R, C> + Clone + Send + Sync + 'static,
+    E: Environment<A, R, C> + Clone + Send + Sync + 'static,
+    A: Action + Clone + Send + Sync + 'static,
+    R: Reward + Send + Sync + 'static,
+    C: Context + Send + Sync + 'static,
+{
+    let means: Option<Arc<HashMap<u32, f64>>> = environment.known_means().map(|known| {
+        Arc::new(
+            all_actions
+                .iter()
+                .map(|action| (action.id(), known.true_mean(action)))
+                .collect(),
+        )
+    });
+    let tables: Vec<PresampledEnvironment<A, R, C>> = (0..num_blocks)
+        .map(|_| {
+            let steps = (0..num_steps)
+                .map(|_| {
+                    let context = environment.get_context();
+                    let rewards = all_actions
+                        .iter()
+                        .map(|action| (action.id(), environment.get_reward(action, &context)))
+                        .collect();
+                    (context, rewards)
+                })
+                .collect();
+            PresampledEnvironment {
+                steps: Arc::new(steps),
+                means: means.clone(),
+                next_step: AtomicUsize::new(0),
+                _phantom: PhantomData,
+            }
+        })
+        .collect();
+
+    policies
+        .par_iter()
+        .map(|policy| {
+            tables
+                .par_iter()
+                .map(|table| {
+                    let mut sim = Simulator::new(policy.fresh(), table.replay());
+                    sim.run_episode(num_steps, all_actions)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Per-step context and reward of every action, sampled ahead of time.
+type PresampledSteps<C, R> = Vec<(C, HashMap<u32, R>)>;
+
+/// Environment replaying a pre-sampled reward table, one row per `get_context` call.
+///
+/// Backs `run_parallel_simulations_paired`; `replay` restarts the table from its first row. Once the
+/// table is exhausted, it wraps around to the first row.
+struct PresampledEnvironment<A, R, C>
+where
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    steps: Arc<PresampledSteps<C, R>>,
+    means: Option<Arc<HashMap<u32, f64>>>,
+    next_step: AtomicUsize,
+    _phantom: PhantomData<A>,
+}
+
+impl<A, R, C> PresampledEnvironment<A, R, C>
+where
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    /// Returns an environment sharing this table, positioned at its first row.
+    fn replay(&self) -> Self {
+        PresampledEnvironment {
+            steps: Arc::clone(&self.steps),
+            means: self.means.clone(),
+            next_step: AtomicUsize::new(0),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the rewards of the row handed out by the latest `get_context` call.
+    fn current_rewards(&self) -> &HashMap<u32, R> {
+        let step = self.next_step.load(Ordering::SeqCst).saturating_sub(1);
+        &self.steps[step % self.steps.len()].1
+    }
+}
+
+impl<A, R, C> Clone for PresampledEnvironment<A, R, C>
+where
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    /// Clones the environment at its current row, sharing the table.
+    fn clone(&self) -> Self {
+        PresampledEnvironment {
+            steps: Arc::clone(&self.steps),
+            means: self.means.clone(),
+            next_step: AtomicUsize::new(self.next_step.load(Ordering::SeqCst)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, R, C> Environment<A, R, C> for PresampledEnvironment<A, R, C>
+where
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    /// Returns the next row's context, wrapping around to the first row once the table is exhausted.
+    fn get_context(&self) -> C {
+        let step = self.next_step.fetch_add(1, Ordering::SeqCst);
+        self.steps[step % self.steps.len()].0.clone()
+    }
+
+    fn get_reward(&self, action: &A, _context: &C) -> R {
+        self.current_rewards()
+            .get(&action.id())
+            .expect("chosen action must be one of all_actions")
+            .clone()
+    }
+
+    fn known_means(&self) -> Option<&dyn KnownMeansEnvironment<A>> {
+        self.means.as_ref().map(|_| self as &dyn KnownMeansEnvironment<A>)
+    }
+}
+
+impl<A, R, C> KnownMeansEnvironment<A> for PresampledEnvironment<A, R, C>
+where
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    fn true_mean(&self, action: &A) -> f64 {
+        self.means.as_ref().expect("true means were not recorded")[&action.id()]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::algorithms::epsilon_greedy::EpsilonGreedyPolicy;
+    use crate::algorithms::thompson_sampling::ThompsonSamplingPolicy;
+    use crate::environments::adversarial::AdversarialEnvironment;
+    use crate::environments::bernoulli::BernoulliEnvironment;
+    use crate::environments::gaussian::GaussianEnvironment;
     use crate::simulation::metrics::analyze_results;
     use crate::traits::entities::{DummyContext, NumericAction};
+    use crate::utils::reward_transform::Scale;
+    use crate::wrappers::logging::LoggingPolicy;
+    use rand_distr::{Distribution, Geometric};
     #[derive(Debug, Clone, PartialEq)]
     struct DummyReward {
         value: f64,
@@ -125,21 +750,25 @@ mod tests {
 
     impl Reward for DummyReward {
         fn value(&self) -> f64 {
-            self.value.clone()
+            self.value
         }
     }
 
-    #[derive(Debug, Clone)]
-    struct DummyEnvironment {
-        name: String,
+    impl From<f64> for DummyReward {
+        fn from(value: f64) -> Self {
+            DummyReward::new(value)
+        }
     }
 
+    #[derive(Debug, Clone)]
+    struct DummyEnvironment;
+
     impl Environment<NumericAction<i32>, DummyReward, DummyContext> for DummyEnvironment {
         fn get_context(&self) -> DummyContext {
             DummyContext
         }
 
-        fn get_reward(&self, action: &NumericAction<i32>, context: &DummyContext) -> DummyReward {
+        fn get_reward(&self, action: &NumericAction<i32>, _context: &DummyContext) -> DummyReward {
             let raw = action.value() + 100;
             DummyReward::new(raw as f64)
         }
@@ -156,9 +785,7 @@ mod tests {
                 0.2, &actions,
             )
             .unwrap();
-        let dummy_env = DummyEnvironment {
-            name: "dummy".to_string(),
-        };
+        let dummy_env = DummyEnvironment;
 
         let mut simulator = Simulator::new(eps_greedy_policy, dummy_env);
 
@@ -179,9 +806,7 @@ mod tests {
                 0.2, &actions,
             )
             .unwrap();
-        let dummy_env = DummyEnvironment {
-            name: "dummy".to_string(),
-        };
+        let dummy_env = DummyEnvironment;
 
         // simulate with 100 different simulators and 1000 steps for each.
         let results = run_parallel_simulations(eps_greedy_policy, dummy_env, &actions, 1000, 100);
@@ -194,4 +819,589 @@ mod tests {
             stats.mean_final_simple_regret, stats.std_final_simple_regret
         );
     }
+
+    #[test]
+    fn test_run_parallel_simulations_progress_reaches_num_runs() {
+        let actions = vec![NumericAction::new(10, "a0"), NumericAction::new(20, "a1")];
+        let eps_greedy_policy =
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                0.2, &actions,
+            )
+            .unwrap();
+        let progress = Arc::new(AtomicUsize::new(0));
+
+        let results = run_parallel_simulations_progress(
+            eps_greedy_policy,
+            DummyEnvironment,
+            &actions,
+            50,
+            16,
+            7,
+            Arc::clone(&progress),
+        );
+
+        assert_eq!(results.len(), 16);
+        assert_eq!(progress.load(Ordering::SeqCst), 16);
+    }
+
+    #[test]
+    fn test_run_parallel_simulations_progress_derives_seeds_per_run() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "a0"),
+            NumericAction::with_id(1, 1i32, "a1"),
+        ];
+        let arms = HashMap::from([(0, (0.2, 1.0)), (1, (0.8, 1.0))]);
+        let env = GaussianEnvironment::<NumericAction<i32>, DummyReward>::new(arms, 42).unwrap();
+        let policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.3, &actions,
+        )
+        .unwrap();
+        let run = |base_seed| {
+            run_parallel_simulations_progress(
+                policy.clone(),
+                env.clone(),
+                &actions,
+                100,
+                8,
+                base_seed,
+                Arc::new(AtomicUsize::new(0)),
+            )
+        };
+
+        let results = run(11);
+        // Every run draws its own random streams...
+        for (index, result) in results.iter().enumerate() {
+            for other in &results[index + 1..] {
+                assert_ne!(result.steps_rewards, other.steps_rewards);
+            }
+        }
+        // ...and repeating the call with the same base seed reproduces them.
+        assert_eq!(run(11), results);
+        assert_ne!(run(12), results);
+    }
+
+    #[test]
+    fn test_run_seed_does_not_collide_across_base_seeds() {
+        assert_ne!(run_seed(0, 1), run_seed(1, 0));
+        assert_ne!(run_seed(0, 0), run_seed(0, 1));
+        assert_eq!(run_seed(7, 3), run_seed(7, 3));
+    }
+
+    #[test]
+    fn test_run_parallel_simulations_keeps_policy_seed() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "a0"),
+            NumericAction::with_id(1, 1i32, "a1"),
+        ];
+        let arms = HashMap::from([(0, (0.2, 1.0)), (1, (0.8, 1.0))]);
+        let env = GaussianEnvironment::<NumericAction<i32>, DummyReward>::new(arms, 42).unwrap();
+        let policy =
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new_seeded(
+                0.3, &actions, 9,
+            )
+            .unwrap();
+
+        let expected = Simulator::new(policy.clone(), env.clone()).run_episode(100, &actions);
+        let results = run_parallel_simulations(policy, env, &actions, 100, 3);
+        assert!(results.iter().all(|result| *result == expected));
+    }
+
+    #[test]
+    fn test_run_episode_traced() {
+        let actions = vec![
+            NumericAction::new(10, "a0"),
+            NumericAction::new(20, "a1"),
+            NumericAction::new(30, "a2"),
+        ];
+        let eps_greedy_policy =
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                0.5, &actions,
+            )
+            .unwrap();
+        let mut simulator = Simulator::new(eps_greedy_policy, DummyEnvironment);
+
+        let (results, trace) = simulator.run_episode_traced(100, &actions);
+        assert_eq!(trace.len(), 100);
+        assert_eq!(results.steps_rewards.len(), 100);
+        for (action_id, reward) in trace.iter().zip(&results.steps_rewards) {
+            let action = actions.iter().find(|a| a.id() == *action_id).unwrap();
+            assert_eq!(*reward, (action.value() + 100) as f64);
+        }
+    }
+
+    #[test]
+    fn test_exploration_count_matches_epsilon() {
+        let actions: Vec<NumericAction<i32>> =
+            (0..10).map(|value| NumericAction::new(value * 10, "arm")).collect();
+        let epsilon = 0.2;
+        let eps_greedy_policy =
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                epsilon, &actions,
+            )
+            .unwrap();
+        let mut simulator = Simulator::new(eps_greedy_policy, DummyEnvironment);
+        let num_steps = 2000;
+
+        let results = simulator.run_episode(num_steps, &actions);
+        assert_eq!(results.chosen_propensities.len(), num_steps);
+
+        // Exploratory picks of non-greedy arms have propensity epsilon / 10; greedy picks exceed 1 - epsilon.
+        let explored = results.exploration_count(0.5) as f64;
+        let expected = epsilon * num_steps as f64;
+        assert!(
+            (explored - expected).abs() < 0.25 * expected,
+            "explored {} steps, expected about {}",
+            explored,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_run_episode_stored_f32_matches_f64() {
+        let actions = vec![
+            NumericAction::new(10, "a0"),
+            NumericAction::new(20, "a1"),
+            NumericAction::new(30, "a2"),
+        ];
+        let eps_greedy_policy =
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                0.3, &actions,
+            )
+            .unwrap();
+        let mut simulator_f64 = Simulator::new(eps_greedy_policy.clone(), DummyEnvironment);
+        let mut simulator_f32 = Simulator::new(eps_greedy_policy, DummyEnvironment);
+
+        let results_f64 = simulator_f64.run_episode_stored::<f64>(500, &actions);
+        let results_f32 = simulator_f32.run_episode_stored::<f32>(500, &actions);
+
+        assert_eq!(results_f64.chosen_action_ids, results_f32.chosen_action_ids);
+        for (wide, narrow) in results_f64.steps_regret.iter().zip(&results_f32.steps_regret) {
+            assert!((wide - *narrow as f64).abs() <= wide.abs() * f32::EPSILON as f64);
+        }
+
+        let stats_f64 = analyze_results(&[results_f64]);
+        let stats_f32 = analyze_results(&[results_f32]);
+        assert_eq!(
+            stats_f64.average_cumulative_reward,
+            stats_f32.average_cumulative_reward
+        );
+        let final_f64 = *stats_f64.average_step_regrets.last().unwrap();
+        let final_f32 = *stats_f32.average_step_regrets.last().unwrap();
+        assert!((final_f64 - final_f32).abs() <= final_f64.abs() * f32::EPSILON as f64);
+    }
+
+    #[test]
+    fn test_run_until_best_identified_stops_early() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "weak"),
+            NumericAction::with_id(1, 1i32, "strong"),
+        ];
+        let probabilities = [(0, 0.1), (1, 0.9)];
+        let env = BernoulliEnvironment::new(probabilities.into_iter().collect(), 42).unwrap();
+        let policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            1.0, &actions,
+        )
+        .unwrap();
+        let mut simulator = Simulator::new(policy, env);
+
+        let max_steps = 10_000;
+        let (best, steps) = simulator
+            .run_until_best_identified(max_steps, &actions, 0.05)
+            .unwrap()
+            .expect("best arm should be identified");
+        assert_eq!(best.id(), 1);
+        assert!(steps < max_steps / 10, "took {} steps", steps);
+    }
+
+    #[test]
+    fn test_run_until_best_identified_rejects_unknown_action() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "a0"),
+            NumericAction::with_id(1, 20i32, "a1"),
+            NumericAction::with_id(2, 30i32, "a2"),
+        ];
+        let policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            1.0, &actions,
+        )
+        .unwrap();
+        let mut simulator = Simulator::new(policy, DummyEnvironment);
+
+        // The policy explores arm 2, which the caller left out of `all_actions`.
+        let err = simulator.run_until_best_identified(1000, &actions[..2], 0.05).unwrap_err();
+        assert_eq!(err, OctopusError::UnknownAction { action_id: 2 });
+    }
+
+    #[test]
+    fn test_run_episode_random_delay_matches_no_delay() {
+        let actions = vec![
+            NumericAction::with_id(0, 10, "a0"),
+            NumericAction::with_id(1, 20, "a1"),
+            NumericAction::with_id(2, 30, "a2"),
+        ];
+        // With epsilon 1.0 every choice is uniform exploration, so the action sequence does not
+        // depend on when rewards arrive.
+        let policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            1.0, &actions,
+        )
+        .unwrap();
+
+        let mut immediate = Simulator::new(policy.clone(), DummyEnvironment);
+        let expected = immediate.run_episode(500, &actions);
+
+        let geometric = Geometric::new(0.3).unwrap();
+        let mut delayed = Simulator::new(policy, DummyEnvironment);
+        let results = delayed.run_episode_random_delay(
+            500,
+            &actions,
+            |rng| geometric.sample(rng) as usize,
+            7,
+        );
+
+        assert_eq!(results, expected);
+        assert_eq!(
+            delayed.policy.checkpoint().unwrap(),
+            immediate.policy.checkpoint().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_known_means_reduce_regret_variance() {
+        /// Hides the true means of a Gaussian environment, forcing sampled regret.
+        #[derive(Clone)]
+        struct SampledOnly(GaussianEnvironment<NumericAction<i32>, DummyReward>);
+
+        impl Environment<NumericAction<i32>, DummyReward, DummyContext> for SampledOnly {
+            fn get_context(&self) -> DummyContext {
+                DummyContext
+            }
+
+            fn get_reward(
+                &self,
+                action: &NumericAction<i32>,
+                context: &DummyContext,
+            ) -> DummyReward {
+                self.0.get_reward(action, context)
+            }
+
+            fn get_optimal_reward(
+                &self,
+                _context: &DummyContext,
+                actions: &[NumericAction<i32>],
+            ) -> DummyReward {
+                // Sample the best arm rather than reporting its mean.
+                let best = actions
+                    .iter()
+                    .max_by(|a, b| self.0.mean(a.id()).partial_cmp(&self.0.mean(b.id())).unwrap())
+                    .unwrap();
+                self.0.get_reward(best, &DummyContext)
+            }
+        }
+
+        fn final_regret_variance<E>(env: &E, actions: &[NumericAction<i32>]) -> f64
+        where
+            E: Environment<NumericAction<i32>, DummyReward, DummyContext>,
+        {
+            let regrets: Vec<f64> = (0..200)
+                .map(|run| {
+                    let policy =
+                        EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                            0.1, actions,
+                        )
+                        .unwrap();
+                    let env = env.clone();
+                    // Advance the shared sampler so each run sees different rewards.
+                    for _ in 0..run {
+                        env.get_reward(&actions[0], &DummyContext);
+                    }
+                    let mut simulator = Simulator::new(policy, env);
+                    *simulator.run_episode(100, actions).steps_regret.last().unwrap()
+                })
+                .collect();
+            let mean = regrets.iter().sum::<f64>() / regrets.len() as f64;
+            regrets.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / regrets.len() as f64
+        }
+
+        let actions = vec![
+            NumericAction::with_id(0, 0, "a0"),
+            NumericAction::with_id(1, 1, "a1"),
+        ];
+        let arms = [(0, (1.0, 1.0)), (1, (1.5, 1.0))];
+        let gaussian = GaussianEnvironment::new(arms.into_iter().collect(), 42).unwrap();
+
+        let exact = final_regret_variance(&gaussian, &actions);
+        let sampled = final_regret_variance(&SampledOnly(gaussian), &actions);
+        assert!(exact < sampled, "exact: {}, sampled: {}", exact, sampled);
+    }
+
+    #[test]
+    fn test_run_episode_normalized_feeds_scaled_rewards() {
+        /// Arm 0 always pays 10.0 and arm 1 always pays 50.0.
+        #[derive(Clone)]
+        struct ScaledEnvironment;
+
+        impl Environment<NumericAction<i32>, DummyReward, DummyContext> for ScaledEnvironment {
+            fn get_context(&self) -> DummyContext {
+                DummyContext
+            }
+
+            fn get_reward(
+                &self,
+                action: &NumericAction<i32>,
+                _context: &DummyContext,
+            ) -> DummyReward {
+                DummyReward::new(if action.id() == 0 { 10.0 } else { 50.0 })
+            }
+
+            fn reward_scale(&self) -> (f64, f64) {
+                (10.0, 50.0)
+            }
+        }
+
+        let actions = vec![
+            NumericAction::with_id(0, 0, "low"),
+            NumericAction::with_id(1, 1, "high"),
+        ];
+        let policy = LoggingPolicy::new(
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                1.0, &actions,
+            )
+            .unwrap(),
+            |_: &DummyContext| String::new(),
+        );
+        let mut simulator = Simulator::new(policy, ScaledEnvironment);
+        let results = simulator.run_episode_normalized(200, &actions);
+
+        let log = simulator.policy.decision_log();
+        assert_eq!(log.len(), 200);
+        for (record, &original) in log.iter().zip(&results.steps_rewards) {
+            let expected = if record.action_id == 0 { 0.0 } else { 1.0 };
+            assert_eq!(record.reward_value, expected);
+            assert_eq!(original, if record.action_id == 0 { 10.0 } else { 50.0 });
+        }
+
+        // Regret is on the original scale: 40.0 for every pull of the low arm.
+        let low_pulls = log.iter().filter(|record| record.action_id == 0).count();
+        assert_eq!(results.cumulative_optimal_reward, 200.0 * 50.0);
+        assert_eq!(
+            *results.steps_regret.last().unwrap(),
+            40.0 * low_pulls as f64
+        );
+    }
+
+    #[test]
+    fn test_run_episode_transformed_feeds_shaped_rewards() {
+        let actions = vec![NumericAction::new(10, "a0"), NumericAction::new(20, "a1")];
+        let policy = LoggingPolicy::new(
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                0.5, &actions,
+            )
+            .unwrap(),
+            |_: &DummyContext| String::new(),
+        );
+        let transform: Box<dyn RewardTransform> = Box::new(Scale { factor: 0.01 });
+        let mut simulator = Simulator::new(policy, DummyEnvironment);
+        let results = simulator.run_episode_transformed(50, &actions, transform.as_ref());
+
+        let log = simulator.policy.decision_log();
+        for (record, &original) in log.iter().zip(&results.steps_rewards) {
+            assert_eq!(
+                original,
+                if record.action_id == actions[0].id() {
+                    110.0
+                } else {
+                    120.0
+                }
+            );
+            assert!((record.reward_value - original * 0.01).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_run_parallel_simulations_paired_shares_rewards() {
+        let actions = vec![
+            NumericAction::with_id(0, 0, "a0"),
+            NumericAction::with_id(1, 1, "a1"),
+            NumericAction::with_id(2, 2, "a2"),
+        ];
+        let arms = HashMap::from([(0, (0.2, 1.0)), (1, (0.5, 1.0)), (2, (0.4, 1.0))]);
+        let environment = GaussianEnvironment::new(arms, 42).unwrap();
+        let greedy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.1, &actions,
+        )
+        .unwrap();
+        let exploratory =
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                0.5, &actions,
+            )
+            .unwrap();
+
+        let results = run_parallel_simulations_paired(
+            vec![greedy.clone(), greedy, exploratory],
+            environment,
+            &actions,
+            200,
+            4,
+        );
+
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|blocks| blocks.len() == 4));
+        // The same policy on the same tables reproduces its results exactly.
+        assert_eq!(results[0], results[1]);
+        // Blocks use different samples.
+        assert_ne!(results[0][0].steps_rewards, results[0][1].steps_rewards);
+        // Different policies see the same reward whenever they make the same choice.
+        let mut shared_choices = 0;
+        for (greedy_block, exploratory_block) in results[0].iter().zip(&results[2]) {
+            assert_ne!(
+                greedy_block.chosen_action_ids,
+                exploratory_block.chosen_action_ids
+            );
+            for step in 0..200 {
+                if greedy_block.chosen_action_ids[step] == exploratory_block.chosen_action_ids[step]
+                {
+                    shared_choices += 1;
+                    assert_eq!(
+                        greedy_block.steps_rewards[step],
+                        exploratory_block.steps_rewards[step]
+                    );
+                }
+            }
+        }
+        assert!(shared_choices > 0);
+    }
+
+    #[test]
+    fn test_run_parallel_simulations_paired_repeats_reseeding_policies() {
+        let actions = vec![
+            NumericAction::with_id(0, 0, "a0"),
+            NumericAction::with_id(1, 1, "a1"),
+        ];
+        let arms = HashMap::from([(0, (0.3, 0.5)), (1, (0.6, 0.5))]);
+        let environment = GaussianEnvironment::new(arms, 42).unwrap();
+        // `ThompsonSamplingPolicy::clone` draws a new seed, but `fresh` keeps the original one.
+        let thompson =
+            ThompsonSamplingPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+                &actions, 7,
+            )
+            .unwrap();
+
+        let results = run_parallel_simulations_paired(
+            vec![thompson.fresh(), thompson],
+            environment,
+            &actions,
+            100,
+            2,
+        );
+        // Thompson reports no propensities, and NaN != NaN, so compare choices and rewards.
+        for (first, second) in results[0].iter().zip(&results[1]) {
+            assert_eq!(first.chosen_action_ids, second.chosen_action_ids);
+            assert_eq!(first.steps_rewards, second.steps_rewards);
+        }
+    }
+
+    #[test]
+    fn test_presampled_environment_clone_keeps_position() {
+        let steps = vec![
+            (DummyContext, HashMap::from([(0, DummyReward::new(1.0))])),
+            (DummyContext, HashMap::from([(0, DummyReward::new(2.0))])),
+        ];
+        type Table = PresampledEnvironment<NumericAction<i32>, DummyReward, DummyContext>;
+        let environment = Table {
+            steps: Arc::new(steps),
+            means: None,
+            next_step: AtomicUsize::new(0),
+            _phantom: PhantomData,
+        };
+        let action = NumericAction::with_id(0, 0, "a0");
+        let reward = |env: &Table| {
+            let context = env.get_context();
+            env.get_reward(&action, &context).value()
+        };
+
+        assert_eq!(reward(&environment), 1.0);
+        // A clone continues from the same row; only `replay` rewinds.
+        let clone = environment.clone();
+        assert_eq!(reward(&clone), 2.0);
+        assert_eq!(reward(&environment.replay()), 1.0);
+        // The table wraps around once exhausted.
+        assert_eq!(reward(&environment), 2.0);
+        assert_eq!(reward(&environment), 1.0);
+    }
+
+    #[test]
+    fn test_run_episode_fixed_best_vs_per_step_optimum() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "a0"),
+            NumericAction::with_id(1, 1i32, "a1"),
+        ];
+        let policy = || {
+            EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new_seeded(
+                1.0, &actions, 5,
+            )
+            .unwrap()
+        };
+
+        // Stationary: the per-step optimum is always the same arm, so both regrets agree.
+        let arms = HashMap::from([(0, (0.2, 0.1)), (1, (0.8, 0.1))]);
+        let gaussian =
+            GaussianEnvironment::<NumericAction<i32>, DummyReward>::new(arms, 42).unwrap();
+        let moving = Simulator::new(policy(), gaussian.clone()).run_episode(1000, &actions);
+        let fixed = Simulator::new(policy(), gaussian).run_episode_fixed_best(1000, &actions);
+        let (moving_regret, fixed_regret) = (
+            *moving.steps_regret.last().unwrap(),
+            *fixed.steps_regret.last().unwrap(),
+        );
+        assert!(
+            (moving_regret - fixed_regret).abs() < 1e-9,
+            "{} vs {}",
+            moving_regret,
+            fixed_regret
+        );
+
+        // Non-stationary: arm 0 pays in the first half, arm 1 in the second half. The moving
+        // optimum earns 1.0 every step, while any fixed arm earns only half of the total.
+        let num_steps = 1000;
+        let switching = || {
+            AdversarialEnvironment::<NumericAction<i32>, DummyReward>::new(
+                move |action_id, step, _previous| {
+                    let first_half = step < num_steps / 2;
+                    if (action_id == 0) == first_half {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                },
+            )
+        };
+        let moving = Simulator::new(policy(), switching()).run_episode(num_steps, &actions);
+        let fixed =
+            Simulator::new(policy(), switching()).run_episode_fixed_best(num_steps, &actions);
+        assert_eq!(moving.cumulative_optimal_reward, num_steps as f64);
+        assert_eq!(fixed.cumulative_optimal_reward, (num_steps / 2) as f64);
+        assert_eq!(moving.cumulative_reward, fixed.cumulative_reward);
+        assert_eq!(
+            moving.steps_regret.last().unwrap() - fixed.steps_regret.last().unwrap(),
+            (num_steps / 2) as f64
+        );
+    }
+
+    #[test]
+    fn test_run_for_duration_stops_after_budget() {
+        let actions = vec![NumericAction::new(10, "a0"), NumericAction::new(20, "a1")];
+        let policy = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.2, &actions,
+        )
+        .unwrap();
+        let mut simulator = Simulator::new(policy, DummyEnvironment);
+
+        let start = Instant::now();
+        let results = simulator.run_for_duration(Duration::from_millis(5), &actions);
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert!(!results.steps_rewards.is_empty());
+        assert_eq!(results.steps_regret.len(), results.steps_rewards.len());
+        assert_eq!(results.chosen_action_ids.len(), results.steps_rewards.len());
+
+        let results = simulator.run_for_duration(Duration::ZERO, &actions);
+        assert!(results.steps_rewards.is_empty());
+    }
 }
@@ -87,16 +87,45 @@ pub struct ActionStorage<A: Action>(HashMap<u32, A>);
 impl<A: Action + Clone> ActionStorage<A> {
     /// Creates a new ActionStorage from a slice of actions.
     pub fn new(initial_actions: &[A]) -> Result<Self, OctopusError> {
-        let actions = initial_actions
-            .into_iter()
-            .map(|action| (action.id(), action.clone()))
-            .collect();
-        Ok(ActionStorage { 0: actions })
+        let actions = initial_actions.iter().map(|action| (action.id(), action.clone())).collect();
+        Ok(ActionStorage(actions))
     }
     /// Returns all actions as a vector.
     pub fn get_all_actions(&self) -> Vec<A> {
         self.0.values().cloned().collect()
     }
+
+    /// Returns all action IDs, sorted ascending.
+    pub fn sorted_ids(&self) -> Vec<u32> {
+        let mut action_ids: Vec<u32> = self.0.keys().copied().collect();
+        action_ids.sort_unstable();
+        action_ids
+    }
+
+    /// Stores `action` under its ID, returning the action it replaces, if any.
+    pub fn insert(&mut self, action: A) -> Option<A> {
+        self.0.insert(action.id(), action)
+    }
+
+    /// Removes and returns the action with the given ID, if stored.
+    pub fn remove(&mut self, action_id: u32) -> Option<A> {
+        self.0.remove(&action_id)
+    }
+
+    /// Returns the entries of `map` whose key is a stored action ID, dropping any others.
+    ///
+    /// Lenient `update`s record statistics for unknown IDs; checkpoints filter them out with this.
+    pub(crate) fn known_entries<V: Clone>(&self, map: &HashMap<u32, V>) -> HashMap<u32, V> {
+        map.iter()
+            .filter(|(action_id, _)| self.0.contains_key(action_id))
+            .map(|(&action_id, value)| (action_id, value.clone()))
+            .collect()
+    }
+
+    /// Returns true if `other` is keyed by exactly the stored action IDs.
+    pub(crate) fn has_same_ids<V>(&self, other: &HashMap<u32, V>) -> bool {
+        self.0.len() == other.len() && other.keys().all(|action_id| self.0.contains_key(action_id))
+    }
 }
 
 impl<A: Action> Deref for ActionStorage<A> {
@@ -120,6 +149,52 @@ pub trait Reward: Clone + Send + Sync + 'static {
     fn value(&self) -> f64;
 }
 
+/// A reward wrapping a single number, e.g. `ScalarReward(3u32)` or `ScalarReward(2.5f64)`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ScalarReward<T>(pub T);
+
+impl<T> Reward for ScalarReward<T>
+where
+    T: Into<f64> + Clone + Send + Sync + 'static,
+{
+    fn value(&self) -> f64 {
+        self.0.clone().into()
+    }
+}
+
+/// Lets `ScalarReward<f64>` be used with environments that build rewards from sampled values.
+impl From<f64> for ScalarReward<f64> {
+    fn from(value: f64) -> Self {
+        ScalarReward(value)
+    }
+}
+
+/// A success/failure reward, worth 1.0 on success and 0.0 on failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BinaryReward(pub bool);
+
+impl BinaryReward {
+    /// Draws a success with probability `p`, e.g. to simulate Bernoulli feedback.
+    ///
+    /// Returns an error if `p` is not in `[0, 1]`.
+    pub fn from_probability<G: Rng + ?Sized>(p: f64, rng: &mut G) -> Result<Self, OctopusError> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "p".to_string(),
+                value: p.to_string(),
+                expected_range: "0.0 to 1.0 inclusive".to_string(),
+            });
+        }
+        Ok(BinaryReward(rng.random_bool(p)))
+    }
+}
+
+impl Reward for BinaryReward {
+    fn value(&self) -> f64 {
+        if self.0 { 1.0 } else { 0.0 }
+    }
+}
+
 /// Represents the contextual information available to the bandit algorithm.
 ///
 /// The context is typically converted to an ndarray for use in contextual algorithms.
@@ -131,7 +206,7 @@ pub trait Context: Clone + Send + Sync + 'static {
 }
 
 /// Dummy context for non-contextual bandits or testing.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct DummyContext;
 
 impl Context for DummyContext {
@@ -140,3 +215,57 @@ impl Context for DummyContext {
         Array1::from_vec(vec![0.0])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scalar_reward_value() {
+        assert_eq!(ScalarReward(-3i32).value(), -3.0);
+        assert_eq!(ScalarReward(200u8).value(), 200.0);
+        assert_eq!(ScalarReward(2.5f64).value(), 2.5);
+        assert_eq!(ScalarReward::from(0.25), ScalarReward(0.25f64));
+    }
+
+    #[test]
+    fn test_binary_reward_from_probability_matches_rate() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let num_draws = 20_000;
+        let successes = (0..num_draws)
+            .filter(|_| BinaryReward::from_probability(0.3, &mut rng).unwrap().0)
+            .count();
+        let rate = successes as f64 / num_draws as f64;
+        assert!((rate - 0.3).abs() < 0.01, "empirical rate {}", rate);
+
+        assert_eq!(BinaryReward(true).value(), 1.0);
+        assert_eq!(BinaryReward(false).value(), 0.0);
+        assert_eq!(
+            BinaryReward::from_probability(1.0, &mut rng),
+            Ok(BinaryReward(true))
+        );
+        assert!(BinaryReward::from_probability(1.5, &mut rng).is_err());
+        assert!(BinaryReward::from_probability(f64::NAN, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_action_storage_insert_and_remove() {
+        let mut storage = ActionStorage::new(&[NumericAction::with_id(0, 1i32, "a")]).unwrap();
+
+        assert_eq!(storage.insert(NumericAction::with_id(1, 2i32, "b")), None);
+        assert_eq!(storage.sorted_ids(), vec![0, 1]);
+
+        // Inserting an existing ID overwrites it and returns the previous action.
+        let replaced = storage.insert(NumericAction::with_id(1, 3i32, "c")).unwrap();
+        assert_eq!(replaced.value, 2);
+        assert_eq!(storage.len(), 2);
+        assert_eq!(storage.get(&1).unwrap().value, 3);
+
+        assert_eq!(storage.remove(1).unwrap().value, 3);
+        assert!(storage.remove(1).is_none());
+        assert_eq!(storage.sorted_ids(), vec![0]);
+    }
+}
@@ -25,4 +25,36 @@ where
             .max_by(|r1, r2| r1.value().partial_cmp(&r2.value()).unwrap())
             .expect("No actions provided")
     }
+
+    /// Returns the `(min, max)` range of the rewards this environment produces.
+    ///
+    /// `Simulator::run_episode_normalized` uses it to map rewards linearly into `[0, 1]` before
+    /// feeding them to the policy; `min` maps to 0.0 and `max` to 1.0. The range must satisfy
+    /// `min < max`. The default `(0.0, 1.0)` declares rewards as already normalized.
+    fn reward_scale(&self) -> (f64, f64) {
+        (0.0, 1.0)
+    }
+
+    /// Returns this environment's ground-truth arm means, if it exposes them.
+    ///
+    /// Environments implementing `KnownMeansEnvironment` should override this to return `Some(self)`,
+    /// which lets the simulator compute exact expected regret instead of sampling `get_optimal_reward`.
+    fn known_means(&self) -> Option<&dyn KnownMeansEnvironment<A>> {
+        None
+    }
+
+    /// Replaces the seed of the environment's RNG and restarts its random stream from it.
+    ///
+    /// Lets parallel runs draw independent but reproducible rewards from clones of one environment.
+    /// The default implementation does nothing, which suits deterministic environments.
+    fn reseed(&mut self, _seed: u64) {}
+}
+
+/// An environment that knows the true expected reward of each arm.
+pub trait KnownMeansEnvironment<A>
+where
+    A: Action,
+{
+    /// Returns the expected reward of the given action.
+    fn true_mean(&self, action: &A) -> f64;
 }
@@ -1,4 +1,115 @@
+use std::collections::HashMap;
+
 use crate::traits::entities::{Action, Context, Reward};
+use crate::utils::error::OctopusError;
+
+/// Optimization direction of a policy's reward signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// Higher rewards are better (the default).
+    #[default]
+    Maximize,
+    /// Lower rewards are better, e.g. when the reward is a cost.
+    Minimize,
+}
+
+impl Direction {
+    /// Returns true if `candidate` is strictly better than `incumbent` in this direction.
+    pub fn prefers(&self, candidate: f64, incumbent: f64) -> bool {
+        match self {
+            Direction::Maximize => candidate > incumbent,
+            Direction::Minimize => candidate < incumbent,
+        }
+    }
+
+    /// Returns the worst possible score in this direction, useful as the start of an argmax/argmin.
+    pub fn worst(&self) -> f64 {
+        match self {
+            Direction::Maximize => f64::NEG_INFINITY,
+            Direction::Minimize => f64::INFINITY,
+        }
+    }
+}
+
+/// Snapshot of a policy's learned state, used by `BanditPolicy::checkpoint` and `BanditPolicy::restore`.
+///
+/// Each variant captures exactly what its policy needs to resume learning, including the RNG seed.
+/// Configuration (e.g. epsilon or the action set) is not included; restore into an identically configured policy.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolicyCheckpoint {
+    /// State of an `EpsilonGreedyPolicy`, including its explore/exploit decision counts.
+    EpsilonGreedy {
+        counts: HashMap<u32, u64>,
+        sum_rewards: HashMap<u32, f64>,
+        total_pulls: u64,
+        explore_count: u64,
+        exploit_count: u64,
+        seed: u64,
+    },
+    /// State of a `ThompsonSamplingPolicy`.
+    ThompsonSampling {
+        alpha_params: HashMap<u32, f64>,
+        beta_params: HashMap<u32, f64>,
+        observed_range: Option<(f64, f64)>,
+        seed: u64,
+    },
+    /// State of a `LinearThompsonPolicy`: each arm's posterior mean and row-major covariance.
+    LinearThompson {
+        means: HashMap<u32, Vec<f64>>,
+        covariances: HashMap<u32, Vec<f64>>,
+        seed: u64,
+    },
+    /// State of a `Ucb1Policy`, which has no RNG.
+    Ucb1 {
+        counts: HashMap<u32, u64>,
+        sum_rewards: HashMap<u32, f64>,
+        total_pulls: u64,
+    },
+    /// State of a `SoftUCBPolicy`.
+    SoftUCB {
+        counts: HashMap<u32, u64>,
+        sum_rewards: HashMap<u32, f64>,
+        total_pulls: u64,
+        seed: u64,
+    },
+    /// State of a `SoftmaxPolicy`.
+    Softmax {
+        counts: HashMap<u32, u64>,
+        sum_rewards: HashMap<u32, f64>,
+        total_pulls: u64,
+        seed: u64,
+    },
+    /// State of a `PerturbedHistoryPolicy`.
+    PerturbedHistory {
+        counts: HashMap<u32, u64>,
+        sum_rewards: HashMap<u32, f64>,
+        seed: u64,
+    },
+    /// State of a `SuccessiveEliminationPolicy`, including the arms still active.
+    SuccessiveElimination {
+        counts: HashMap<u32, u64>,
+        sum_rewards: HashMap<u32, f64>,
+        active_ids: Vec<u32>,
+        step: u64,
+    },
+    /// State of a `RoundRobinPolicy`: the number of selections made so far.
+    RoundRobin { step: u64 },
+    /// State of an `OraclePolicy`, which has no learned state.
+    Oracle,
+    /// State of a `RandomPolicy`: only its RNG seed, as it does not learn.
+    Random { seed: u64 },
+    /// State of a `FairnessWrapper`, wrapping the checkpoint of its inner policy.
+    Fairness {
+        pulls: HashMap<u32, u64>,
+        seed: u64,
+        inner: Box<PolicyCheckpoint>,
+    },
+    /// State of a `WarmupWrapper`, wrapping the checkpoint of its inner policy.
+    Warmup {
+        pulls: HashMap<u32, u64>,
+        inner: Box<PolicyCheckpoint>,
+    },
+}
 
 /// Core trait for all Multi-Armed Bandit (MAB) algorithms and policies.
 ///
@@ -15,11 +126,172 @@ where
     /// For non-contextual policies, the context may be ignored.
     fn choose_action(&self, context: &C) -> A;
 
+    /// Selects an action and returns it alongside the internal score used to pick it.
+    ///
+    /// The meaning of the score is policy-specific (e.g. an average reward or a posterior sample).
+    /// The default implementation delegates to `choose_action` and reports `f64::NAN`.
+    fn choose_action_scored(&self, context: &C) -> (A, f64) {
+        (self.choose_action(context), f64::NAN)
+    }
+
+    /// Selects an action among those satisfying `predicate`, e.g. to enforce business rules such
+    /// as "only in-budget arms". Returns `None` if no allowed action can be selected.
+    ///
+    /// Policies that support it filter the candidates before their argmax or sampling step. The
+    /// default implementation only checks the unconstrained `choose_action` pick and returns `None`
+    /// if it is excluded.
+    fn choose_action_where(&self, context: &C, predicate: impl Fn(&A) -> bool) -> Option<A> {
+        Some(self.choose_action(context)).filter(|action| predicate(action))
+    }
+
     /// Updates the policy's internal state based on the observed outcome.
     fn update(&mut self, context: &C, action: &A, reward: &R);
 
-    /// Resets the policy to its initial state (for repeated experiments).
+    /// Updates the policy like `update`, but rejects actions the policy does not manage.
+    ///
+    /// `update` stays lenient for compatibility; use this to keep typos in action IDs from
+    /// creating phantom statistics. Returns `OctopusError::UnknownAction` and leaves the policy
+    /// untouched if `action` is not among `action_ids`.
+    fn try_update(&mut self, context: &C, action: &A, reward: &R) -> Result<(), OctopusError> {
+        if !self.action_ids().contains(&action.id()) {
+            return Err(OctopusError::UnknownAction {
+                action_id: action.id(),
+            });
+        }
+        self.update(context, action, reward);
+        Ok(())
+    }
+
+    /// Folds the learned state of `other`, e.g. a replica trained on another data shard, into this policy.
+    ///
+    /// Policies that support it end up as if they had been trained on both data sets. Returns an
+    /// error if the action sets differ. The default implementation returns
+    /// `OctopusError::UnsupportedOperation`.
+    fn merge(&mut self, _other: &Self) -> Result<(), OctopusError> {
+        Err(OctopusError::UnsupportedOperation {
+            operation: "merge".to_string(),
+        })
+    }
+
+    /// Sets whether learning is frozen.
+    ///
+    /// While frozen, `update` and `merge` leave the learned state untouched, so a trained policy can
+    /// be served (e.g. in a shadow or canary deployment) without changing it. The default
+    /// implementation does nothing, which suits policies that do not learn.
+    fn set_frozen(&mut self, _frozen: bool) {}
+
+    /// Returns true if learning is frozen; see `set_frozen`. The default implementation returns false.
+    fn is_frozen(&self) -> bool {
+        false
+    }
+
+    /// Selects an action without an explicit context, for non-contextual bandits.
+    ///
+    /// Uses `C::default()` as the context.
+    fn choose(&self) -> A
+    where
+        C: Default,
+    {
+        self.choose_action(&C::default())
+    }
+
+    /// Updates the policy without an explicit context, for non-contextual bandits.
+    ///
+    /// Uses `C::default()` as the context.
+    fn observe(&mut self, action: &A, reward: &R)
+    where
+        C: Default,
+    {
+        self.update(&C::default(), action, reward);
+    }
+
+    /// Selects an action, obtains its reward from `reward_fn` and updates the policy with it.
+    ///
+    /// Convenience for online loops where the reward is available immediately. Returns the chosen action.
+    fn choose_and_update(&mut self, context: &C, reward_fn: impl Fn(&A) -> R) -> A {
+        let action = self.choose_action(context);
+        let reward = reward_fn(&action);
+        self.update(context, &action, &reward);
+        action
+    }
+
+    /// Resets the policy's learned state to its initial values (for repeated experiments).
+    ///
+    /// The RNG is NOT reseeded: it continues its current stream. Call `reset_rng` as well to
+    /// replay the exact same sequence of decisions.
     fn reset(&mut self);
 
-    // Optionally, implementors may add persistence methods.
+    /// Reseeds the policy's RNG from its original seed, restarting its random stream.
+    ///
+    /// Learned state is left untouched. The default implementation does nothing, which suits
+    /// deterministic policies.
+    fn reset_rng(&mut self) {}
+
+    /// Replaces the seed of the policy's RNG and restarts its random stream from it.
+    ///
+    /// Later `reset_rng` calls restart from the new seed, and learned state is left untouched. The
+    /// default implementation does nothing, which suits deterministic policies.
+    fn reseed(&mut self, _seed: u64) {}
+
+    /// Returns a copy with identical configuration but no learned state.
+    ///
+    /// The default implementation clones the policy, resets it and restarts its RNG. Policies
+    /// whose `Clone` draws a new random seed override this to keep the original seed.
+    fn fresh(&self) -> Self {
+        let mut policy = self.clone();
+        policy.reset();
+        policy.reset_rng();
+        policy
+    }
+
+    /// Returns the policy's current estimate of the expected reward of `action`.
+    ///
+    /// Returns `None` for actions the policy does not manage. The default implementation returns
+    /// `None` for every action, which suits policies that keep no reward estimates.
+    fn expected_reward(&self, _action: &A) -> Option<f64> {
+        None
+    }
+
+    /// Returns the probability that `choose_action` selects `action` in the policy's current state.
+    ///
+    /// Returns `None` if the policy cannot compute it, which is what the default implementation does.
+    fn selection_probability(&self, _context: &C, _action: &A) -> Option<f64> {
+        None
+    }
+
+    /// Returns the Shannon entropy, in nats, of the distribution of `choose_action` over all actions.
+    ///
+    /// Ranges from 0.0 for a policy committed to a single arm to `ln(K)` for a uniform choice among
+    /// `K` arms. Policies that implement `selection_probability` compute it from those probabilities;
+    /// the default implementation returns `f64::NAN`.
+    fn selection_entropy(&self, _context: &C) -> f64 {
+        f64::NAN
+    }
+
+    /// Returns the IDs of all actions the policy currently manages, sorted ascending.
+    fn action_ids(&self) -> Vec<u32>;
+
+    /// Returns the number of actions the policy currently manages.
+    fn n_actions(&self) -> usize {
+        self.action_ids().len()
+    }
+
+    /// Captures the policy's learned state (statistics, posterior parameters and RNG seed).
+    ///
+    /// The default implementation returns `OctopusError::UnsupportedOperation`.
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        Err(OctopusError::UnsupportedOperation {
+            operation: "checkpoint".to_string(),
+        })
+    }
+
+    /// Restores learned state from a checkpoint and reseeds the RNG from the checkpointed seed.
+    ///
+    /// Returns an error if the checkpoint belongs to a different kind of policy or to a different
+    /// action set. The default implementation returns `OctopusError::UnsupportedOperation`.
+    fn restore(&mut self, _checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        Err(OctopusError::UnsupportedOperation {
+            operation: "restore".to_string(),
+        })
+    }
 }
@@ -0,0 +1,81 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::traits::entities::Context;
+
+/// Number of grid steps per unit used to discretize context features in `hash_context`.
+const GRID_STEPS_PER_UNIT: f64 = 1000.0;
+
+/// Maps a context to one of `n_buckets` bucket ids, for bandits that keep separate statistics per
+/// discretized context.
+///
+/// Each feature of the flattened `to_ndarray` vector is rounded to the nearest multiple of 0.001,
+/// and the rounded vector (including its length) is hashed and reduced modulo `n_buckets`. Contexts
+/// whose features round to the same values always share a bucket. Distinct rounded vectors may
+/// still collide: with a uniform hash, two of them land in the same bucket with probability about
+/// `1 / n_buckets`, so pick `n_buckets` well above the number of contexts you need to tell apart.
+/// NaN features round like 0.0 and infinite features like the largest representable grid value.
+///
+/// The hash is stable within a build, but not guaranteed across Rust versions, so bucket ids
+/// should not be persisted.
+///
+/// Panics if `n_buckets` is 0.
+pub fn hash_context<C: Context>(ctx: &C, n_buckets: usize) -> u64 {
+    assert!(n_buckets > 0, "Must have at least one bucket");
+    let features = ctx.to_ndarray();
+    let mut hasher = DefaultHasher::new();
+    features.len().hash(&mut hasher);
+    for &feature in features.iter() {
+        // `as` saturates at the i64 bounds and maps NaN to 0.
+        ((feature * GRID_STEPS_PER_UNIT).round() as i64).hash(&mut hasher);
+    }
+    hasher.finish() % n_buckets as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{Array1, Ix1};
+    use std::collections::HashSet;
+
+    #[derive(Debug, Clone)]
+    struct FeatureContext(Vec<f64>);
+
+    impl Context for FeatureContext {
+        type DimType = Ix1;
+        fn to_ndarray(&self) -> Array1<f64> {
+            Array1::from_vec(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn test_hash_context_identical_contexts_share_bucket() {
+        let ctx = FeatureContext(vec![0.25, -1.5, 3.0]);
+        let bucket = hash_context(&ctx, 64);
+        assert!(bucket < 64);
+        assert_eq!(hash_context(&ctx.clone(), 64), bucket);
+        // Differences below the rounding grid do not change the bucket.
+        assert_eq!(
+            hash_context(&FeatureContext(vec![0.2500001, -1.5, 3.0]), 64),
+            bucket
+        );
+        assert_eq!(
+            hash_context(&FeatureContext(vec![0.0]), 64),
+            hash_context(&FeatureContext(vec![-0.0]), 64)
+        );
+    }
+
+    #[test]
+    fn test_hash_context_spreads_different_contexts() {
+        let n_buckets = 1 << 20;
+        let buckets: HashSet<u64> = (0..100)
+            .map(|i| hash_context(&FeatureContext(vec![i as f64 * 0.1, 1.0]), n_buckets))
+            .collect();
+        // Collisions are possible but should be rare with many more buckets than contexts.
+        assert!(
+            buckets.len() >= 98,
+            "only {} distinct buckets",
+            buckets.len()
+        );
+    }
+}
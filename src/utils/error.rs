@@ -21,6 +21,43 @@ pub enum OctopusError {
     /// - `collection_name`: The name of the empty collection.
     #[error("Collection '{collection_name}' cannot be empty.")]
     EmptyCollection { collection_name: String },
+
+    /// Error indicating that a policy checkpoint cannot be restored into the target policy.
+    ///
+    /// # Fields
+    /// - `reason`: Why the checkpoint was rejected (e.g. mismatched policy kind or action set).
+    #[error("Invalid checkpoint: {reason}")]
+    InvalidCheckpoint { reason: String },
+
+    /// Error indicating that a data file could not be read.
+    ///
+    /// # Fields
+    /// - `path`: The path of the file.
+    /// - `message`: The underlying I/O error message.
+    #[error("Failed to read '{path}': {message}")]
+    Io { path: String, message: String },
+
+    /// Error indicating that a row of a data file could not be parsed.
+    ///
+    /// # Fields
+    /// - `line`: The 1-based line number of the offending row.
+    /// - `reason`: Why the row was rejected.
+    #[error("Malformed row at line {line}: {reason}")]
+    MalformedRow { line: usize, reason: String },
+
+    /// Error indicating that an action is not managed by the policy it was passed to.
+    ///
+    /// # Fields
+    /// - `action_id`: The ID of the unknown action.
+    #[error("Unknown action id {action_id}")]
+    UnknownAction { action_id: u32 },
+
+    /// Error indicating that a policy does not support the requested operation.
+    ///
+    /// # Fields
+    /// - `operation`: The name of the unsupported operation.
+    #[error("Operation '{operation}' is not supported by this policy")]
+    UnsupportedOperation { operation: String },
     // can add more specific error types here as the library grows, e.g.:
     // #[error("Algorithm specific error: {0}")]
     // AlgorithmError(String),
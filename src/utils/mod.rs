@@ -1 +1,4 @@
+pub mod context;
 pub mod error;
+pub mod reward_transform;
+pub mod special;
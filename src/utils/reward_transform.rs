@@ -0,0 +1,158 @@
+use crate::utils::error::OctopusError;
+
+/// Shapes raw reward values before they reach a policy.
+///
+/// Implementations are stateless maps from a raw reward to the value the policy learns from, so
+/// they can be shared across threads and passed around as `Box<dyn RewardTransform>`.
+pub trait RewardTransform: Send + Sync {
+    /// Maps a raw reward value to its shaped value.
+    fn transform(&self, raw: f64) -> f64;
+}
+
+/// Leaves rewards unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Identity;
+
+impl RewardTransform for Identity {
+    fn transform(&self, raw: f64) -> f64 {
+        raw
+    }
+}
+
+/// Clamps rewards into `[lo, hi]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Clip {
+    lo: f64,
+    hi: f64,
+}
+
+impl Clip {
+    /// Creates a Clip transform for the range `[lo, hi]`.
+    ///
+    /// Returns an error if either bound is NaN or if `lo > hi`.
+    pub fn new(lo: f64, hi: f64) -> Result<Self, OctopusError> {
+        if lo.is_nan() {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "lo".to_string(),
+                value: lo.to_string(),
+                expected_range: "non-NaN value".to_string(),
+            });
+        }
+        if hi.is_nan() || lo > hi {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "hi".to_string(),
+                value: hi.to_string(),
+                expected_range: format!("non-NaN value greater than or equal to lo ({})", lo),
+            });
+        }
+        Ok(Clip { lo, hi })
+    }
+
+    /// Returns the lower bound.
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    /// Returns the upper bound.
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+}
+
+impl RewardTransform for Clip {
+    fn transform(&self, raw: f64) -> f64 {
+        raw.clamp(self.lo, self.hi)
+    }
+}
+
+/// Multiplies rewards by a constant factor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scale {
+    pub factor: f64,
+}
+
+impl RewardTransform for Scale {
+    fn transform(&self, raw: f64) -> f64 {
+        raw * self.factor
+    }
+}
+
+/// Compresses rewards with `ln(1 + raw)`, taming heavy-tailed rewards such as revenue.
+///
+/// Defined for `raw > -1`; as with `f64::ln_1p`, `-1` maps to negative infinity and smaller
+/// values to NaN.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Log1p;
+
+impl RewardTransform for Log1p {
+    fn transform(&self, raw: f64) -> f64 {
+        raw.ln_1p()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity_and_scale() {
+        assert_eq!(Identity.transform(-3.5), -3.5);
+        assert_eq!(Identity.transform(0.0), 0.0);
+
+        let scale = Scale { factor: 0.5 };
+        assert_eq!(scale.transform(4.0), 2.0);
+        assert_eq!(scale.transform(-4.0), -2.0);
+        assert_eq!(Scale { factor: 0.0 }.transform(123.0), 0.0);
+    }
+
+    #[test]
+    fn test_clip_bounds() {
+        let clip = Clip::new(0.0, 1.0).unwrap();
+        assert_eq!(clip.transform(-0.5), 0.0);
+        assert_eq!(clip.transform(0.25), 0.25);
+        assert_eq!(clip.transform(1.0), 1.0);
+        assert_eq!(clip.transform(f64::INFINITY), 1.0);
+        assert_eq!((clip.lo(), clip.hi()), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_clip_rejects_invalid_bounds() {
+        assert_eq!(
+            Clip::new(1.0, 0.0).unwrap_err(),
+            OctopusError::InvalidParameter {
+                parameter_name: "hi".to_string(),
+                value: "0".to_string(),
+                expected_range: "non-NaN value greater than or equal to lo (1)".to_string(),
+            }
+        );
+        assert!(Clip::new(f64::NAN, 1.0).is_err());
+        assert!(Clip::new(0.0, f64::NAN).is_err());
+        assert_eq!(Clip::new(0.5, 0.5).unwrap().transform(3.0), 0.5);
+    }
+
+    #[test]
+    fn test_log1p_near_minus_one() {
+        assert_eq!(Log1p.transform(0.0), 0.0);
+        assert!((Log1p.transform(std::f64::consts::E - 1.0) - 1.0).abs() < 1e-12);
+        // Accurate for tiny inputs, where ln(1 + x) would lose precision.
+        assert!((Log1p.transform(1e-12) - 1e-12).abs() < 1e-24);
+
+        let near = Log1p.transform(-1.0 + 1e-9);
+        assert!(near.is_finite() && near < -20.0);
+        assert_eq!(Log1p.transform(-1.0), f64::NEG_INFINITY);
+        assert!(Log1p.transform(-1.5).is_nan());
+    }
+
+    #[test]
+    fn test_transforms_as_trait_objects() {
+        let transforms: Vec<Box<dyn RewardTransform>> = vec![
+            Box::new(Identity),
+            Box::new(Clip::new(0.0, 2.0).unwrap()),
+            Box::new(Scale { factor: 3.0 }),
+            Box::new(Log1p),
+        ];
+        let shaped: Vec<f64> = transforms.iter().map(|t| t.transform(3.0)).collect();
+        assert_eq!(shaped[..3], [3.0, 2.0, 9.0]);
+        assert!((shaped[3] - 4.0f64.ln()).abs() < 1e-12);
+    }
+}
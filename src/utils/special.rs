@@ -0,0 +1,116 @@
+/// Quantile function of the Beta(a, b) distribution: the `x` in `[0, 1]` with `I_x(a, b) = p`.
+///
+/// Inverts the regularized incomplete beta function by bisection, which is slow but robust for
+/// the occasional monitoring call.
+pub(crate) fn beta_quantile(p: f64, a: f64, b: f64) -> f64 {
+    let (mut lo, mut hi) = (0.0, 1.0);
+    for _ in 0..100 {
+        let mid = 0.5 * (lo + hi);
+        if regularized_incomplete_beta(mid, a, b) < p {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    0.5 * (lo + hi)
+}
+
+/// Regularized incomplete beta function `I_x(a, b)`, evaluated with Lentz's continued fraction.
+pub(crate) fn regularized_incomplete_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    // The continued fraction converges quickly for x < (a + 1) / (a + b + 2); use the symmetry
+    // I_x(a, b) = 1 - I_{1-x}(b, a) otherwise.
+    if x > (a + 1.0) / (a + b + 2.0) {
+        return 1.0 - regularized_incomplete_beta(1.0 - x, b, a);
+    }
+
+    let ln_front = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln();
+    let front = ln_front.exp() / a;
+
+    const TINY: f64 = 1e-300;
+    const EPSILON: f64 = 1e-15;
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut fraction = d;
+
+    for m in 1..=300 {
+        let m = m as f64;
+        for numerator in [
+            m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m)),
+            -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0)),
+        ] {
+            d = 1.0 + numerator * d;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = 1.0 + numerator / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            fraction *= c * d;
+        }
+        if (c * d - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    front * fraction
+}
+
+/// Natural logarithm of the gamma function for `x > 0` (Lanczos approximation, g = 7).
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // Reflection formula.
+        let pi = std::f64::consts::PI;
+        return (pi / (pi * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let mut sum = COEFFICIENTS[0];
+    for (i, &coefficient) in COEFFICIENTS.iter().enumerate().skip(1) {
+        sum += coefficient / (x + i as f64);
+    }
+    let t = x + 7.5;
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ln_gamma_matches_factorials() {
+        assert!((ln_gamma(5.0) - 24f64.ln()).abs() < 1e-12);
+        assert!(ln_gamma(1.0).abs() < 1e-12);
+        assert!((ln_gamma(0.5) - std::f64::consts::PI.sqrt().ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_beta_quantile_reference_points() {
+        // Beta(1, 1) is uniform.
+        assert!((beta_quantile(0.025, 1.0, 1.0) - 0.025).abs() < 1e-12);
+        // Beta(2, 1) has CDF x^2, Beta(1, 3) has CDF 1 - (1 - x)^3.
+        assert!((beta_quantile(0.25, 2.0, 1.0) - 0.5).abs() < 1e-12);
+        assert!((beta_quantile(0.875, 1.0, 3.0) - 0.5).abs() < 1e-12);
+        assert!((regularized_incomplete_beta(0.5, 2.0, 1.0) - 0.25).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,372 @@
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+use crate::traits::entities::{Action, Context, Reward};
+use crate::traits::policy::{BanditPolicy, PolicyCheckpoint};
+use crate::utils::error::OctopusError;
+
+/// The most recent decision, as remembered by a `CachingPolicy`.
+#[derive(Debug, Clone)]
+struct CachedDecision<A> {
+    context_hash: u64,
+    action: A,
+    score: f64,
+    cached_at_step: u64,
+}
+
+/// Selection steps taken so far and the most recent decision.
+#[derive(Debug, Clone)]
+struct DecisionCache<A> {
+    step: u64,
+    decision: Option<CachedDecision<A>>,
+}
+
+impl<A> Default for DecisionCache<A> {
+    fn default() -> Self {
+        DecisionCache {
+            step: 0,
+            decision: None,
+        }
+    }
+}
+
+/// Policy wrapper that reuses the previous decision for identical contexts.
+///
+/// Each call to `choose_action` counts as one step. If the context hashes (via a user-provided
+/// closure) to the same value as the last computed decision, and that decision is less than
+/// `ttl_steps` steps old, the cached action is returned without consulting the inner policy.
+/// Updates are always forwarded to the inner policy and do not invalidate the cache.
+pub struct CachingPolicy<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    inner: P,
+    hash_context: Arc<dyn Fn(&C) -> u64 + Send + Sync>,
+    ttl_steps: u64,
+    cache: Mutex<DecisionCache<A>>,
+    _phantom: PhantomData<R>,
+}
+
+impl<P, A, R, C> CachingPolicy<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    /// Creates a new CachingPolicy.
+    ///
+    /// * `inner` - The policy that makes the actual decisions.
+    /// * `hash_context` - Closure turning a context into a cache key.
+    /// * `ttl_steps` - Number of steps a cached decision stays valid (1 or greater).
+    ///
+    /// Returns an error if `ttl_steps` is 0.
+    pub fn new<F>(inner: P, hash_context: F, ttl_steps: u64) -> Result<Self, OctopusError>
+    where
+        F: Fn(&C) -> u64 + Send + Sync + 'static,
+    {
+        if ttl_steps == 0 {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "ttl_steps".to_string(),
+                value: ttl_steps.to_string(),
+                expected_range: "1 or greater".to_string(),
+            });
+        }
+        Ok(CachingPolicy {
+            inner,
+            hash_context: Arc::new(hash_context),
+            ttl_steps,
+            cache: Mutex::new(DecisionCache::default()),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns a reference to the wrapped policy.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Drops the cached decision, so the next selection consults the inner policy.
+    pub fn invalidate(&self) {
+        self.cache.lock().unwrap().decision = None;
+    }
+}
+
+impl<P, A, R, C> Clone for CachingPolicy<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    fn clone(&self) -> Self {
+        CachingPolicy {
+            inner: self.inner.clone(),
+            hash_context: Arc::clone(&self.hash_context),
+            ttl_steps: self.ttl_steps,
+            cache: Mutex::new(self.cache.lock().unwrap().clone()),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<P, A, R, C> BanditPolicy<A, R, C> for CachingPolicy<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    fn choose_action(&self, context: &C) -> A {
+        self.choose_action_scored(context).0
+    }
+
+    /// Returns the cached action and score if still valid, otherwise asks the inner policy.
+    fn choose_action_scored(&self, context: &C) -> (A, f64) {
+        let context_hash = (self.hash_context)(context);
+        let mut cache = self.cache.lock().unwrap();
+        let step = cache.step;
+        cache.step += 1;
+
+        if let Some(decision) = &cache.decision {
+            if decision.context_hash == context_hash
+                && step - decision.cached_at_step < self.ttl_steps
+            {
+                return (decision.action.clone(), decision.score);
+            }
+        }
+
+        let (action, score) = self.inner.choose_action_scored(context);
+        cache.decision = Some(CachedDecision {
+            context_hash,
+            action: action.clone(),
+            score,
+            cached_at_step: step,
+        });
+        (action, score)
+    }
+
+    /// Asks the inner policy directly; constrained picks neither use nor fill the cache and do not
+    /// count as a step.
+    fn choose_action_where(&self, context: &C, predicate: impl Fn(&A) -> bool) -> Option<A> {
+        self.inner.choose_action_where(context, predicate)
+    }
+
+    /// Forwards the update to the inner policy unconditionally.
+    fn update(&mut self, context: &C, action: &A, reward: &R) {
+        self.inner.update(context, action, reward);
+    }
+
+    /// Resets the inner policy and clears the cache.
+    fn reset(&mut self) {
+        self.inner.reset();
+        *self.cache.lock().unwrap() = DecisionCache::default();
+    }
+
+    fn reset_rng(&mut self) {
+        self.inner.reset_rng();
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.inner.reseed(seed);
+    }
+
+    fn set_frozen(&mut self, frozen: bool) {
+        self.inner.set_frozen(frozen);
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.inner.is_frozen()
+    }
+
+    /// Returns a fresh copy of the inner policy with an empty cache.
+    fn fresh(&self) -> Self {
+        CachingPolicy {
+            inner: self.inner.fresh(),
+            hash_context: Arc::clone(&self.hash_context),
+            ttl_steps: self.ttl_steps,
+            cache: Mutex::new(DecisionCache::default()),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn expected_reward(&self, action: &A) -> Option<f64> {
+        self.inner.expected_reward(action)
+    }
+
+    fn action_ids(&self) -> Vec<u32> {
+        self.inner.action_ids()
+    }
+
+    /// Checkpoints the inner policy; the cache is not part of the learned state.
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        self.inner.checkpoint()
+    }
+
+    /// Restores the inner policy and clears the cache.
+    fn restore(&mut self, checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        self.inner.restore(checkpoint)?;
+        self.invalidate();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::epsilon_greedy::EpsilonGreedyPolicy;
+    use crate::traits::entities::NumericAction;
+    use ndarray::{Array, Array1, Ix1};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct SegmentContext(u64);
+
+    impl Context for SegmentContext {
+        type DimType = Ix1;
+        fn to_ndarray(&self) -> Array<f64, Self::DimType> {
+            Array1::from_vec(vec![self.0 as f64])
+        }
+    }
+
+    type InnerPolicy = EpsilonGreedyPolicy<NumericAction<i32>, DummyReward, SegmentContext>;
+
+    /// Counts how often the wrapped policy is asked for a decision.
+    #[derive(Clone)]
+    struct CountingPolicy {
+        inner: InnerPolicy,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl BanditPolicy<NumericAction<i32>, DummyReward, SegmentContext> for CountingPolicy {
+        fn choose_action(&self, context: &SegmentContext) -> NumericAction<i32> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.inner.choose_action(context)
+        }
+
+        fn update(
+            &mut self,
+            context: &SegmentContext,
+            action: &NumericAction<i32>,
+            reward: &DummyReward,
+        ) {
+            self.inner.update(context, action, reward);
+        }
+
+        fn reset(&mut self) {
+            self.inner.reset();
+        }
+
+        fn action_ids(&self) -> Vec<u32> {
+            self.inner.action_ids()
+        }
+
+        fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+            self.inner.checkpoint()
+        }
+    }
+
+    fn build_policy(
+        ttl_steps: u64,
+    ) -> (
+        CachingPolicy<CountingPolicy, NumericAction<i32>, DummyReward, SegmentContext>,
+        Arc<AtomicUsize>,
+    ) {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "A"),
+            NumericAction::with_id(1, 20i32, "B"),
+        ];
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counting = CountingPolicy {
+            inner: InnerPolicy::new(0.5, &actions).unwrap(),
+            calls: Arc::clone(&calls),
+        };
+        let policy = CachingPolicy::new(counting, |ctx: &SegmentContext| ctx.0, ttl_steps).unwrap();
+        (policy, calls)
+    }
+
+    #[test]
+    fn test_caching_policy_reuses_identical_contexts() {
+        let (mut policy, calls) = build_policy(3);
+        let ctx = SegmentContext(7);
+
+        let first = policy.choose_action(&ctx);
+        for _ in 0..2 {
+            assert_eq!(policy.choose_action(&ctx), first);
+            policy.update(&ctx, &first, &DummyReward(1.0));
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        // Updates reach the inner policy even when the decision was cached.
+        match policy.checkpoint().unwrap() {
+            PolicyCheckpoint::EpsilonGreedy { total_pulls, .. } => assert_eq!(total_pulls, 2),
+            other => panic!("unexpected checkpoint: {:?}", other),
+        }
+
+        // The TTL expires after three steps.
+        policy.choose_action(&ctx);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_caching_policy_choose_action_where_bypasses_cache() {
+        let (policy, calls) = build_policy(2);
+        let ctx = SegmentContext(7);
+
+        let first = policy.choose_action(&ctx);
+        for _ in 0..3 {
+            assert!(policy.choose_action_where(&ctx, |_| true).is_some());
+        }
+        // Every constrained pick reached the inner policy.
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+
+        // Constrained picks did not age the cached decision.
+        assert_eq!(policy.choose_action(&ctx), first);
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn test_caching_policy_recomputes_for_differing_contexts() {
+        let (policy, calls) = build_policy(10);
+        for segment in [1, 2, 1, 2] {
+            policy.choose_action(&SegmentContext(segment));
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+
+        let err = CachingPolicy::new(policy.inner().clone(), |ctx: &SegmentContext| ctx.0, 0)
+            .map(|_| ())
+            .unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::InvalidParameter {
+                parameter_name: "ttl_steps".to_string(),
+                value: "0".to_string(),
+                expected_range: "1 or greater".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_caching_policy_restore_reports_unsupported_inner() {
+        let (mut policy, _) = build_policy(3);
+        let checkpoint = policy.checkpoint().unwrap();
+        // `CountingPolicy` keeps the default `restore`, which is unsupported.
+        assert_eq!(
+            policy.restore(&checkpoint).unwrap_err(),
+            OctopusError::UnsupportedOperation {
+                operation: "restore".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,335 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::traits::entities::{Action, ActionStorage, Context, Reward};
+use crate::traits::policy::{BanditPolicy, PolicyCheckpoint};
+use crate::utils::error::OctopusError;
+
+/// Policy wrapper that guarantees every arm a minimum share of the traffic.
+///
+/// With probability `min_share * K` (for `K` arms), the wrapper overrides the inner policy and
+/// picks the most underserved arm, i.e. the one with the fewest pulls (smallest ID on ties).
+/// Otherwise it delegates to the inner policy. Since every forced pick goes to an arm below its
+/// fair share, each arm's long-run share of pulls stays at or above `min_share`.
+/// All updates are forwarded to the inner policy.
+#[derive(Debug)]
+pub struct FairnessWrapper<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    inner: P,
+    min_share: f64,
+    pulls: HashMap<u32, u64>,
+    action_map: ActionStorage<A>,
+    seed: u64,
+    rng: Mutex<StdRng>,
+    _phantom: PhantomData<(R, C)>,
+}
+
+impl<P, A, R, C> FairnessWrapper<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    /// Creates a new FairnessWrapper with seeded RNG.
+    ///
+    /// * `inner` - The policy to delegate to outside the guaranteed budget.
+    /// * `initial_actions` - Slice of all possible actions.
+    /// * `min_share` - Guaranteed fraction of pulls per arm (0.0 to `1 / K`, with `K` actions).
+    /// * `seed` - Seed for the RNG deciding when the guarantee overrides the inner policy.
+    ///
+    /// Returns an error if actions are empty or `min_share` is out of bounds.
+    pub fn new(
+        inner: P,
+        initial_actions: &[A],
+        min_share: f64,
+        seed: u64,
+    ) -> Result<Self, OctopusError> {
+        if initial_actions.is_empty() {
+            return Err(OctopusError::EmptyCollection {
+                collection_name: "initial_actions".to_string(),
+            });
+        }
+        let action_map = ActionStorage::new(initial_actions)?;
+        let max_share = 1.0 / action_map.len() as f64;
+        if !(0.0..=max_share).contains(&min_share) {
+            return Err(OctopusError::InvalidParameter {
+                parameter_name: "min_share".to_string(),
+                value: min_share.to_string(),
+                expected_range: format!("0.0 to {} (1 / number of actions) inclusive", max_share),
+            });
+        }
+        Ok(FairnessWrapper {
+            inner,
+            min_share,
+            pulls: action_map.keys().map(|&action_id| (action_id, 0)).collect(),
+            action_map,
+            seed,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the number of recorded pulls of each action ID.
+    pub fn pulls(&self) -> &HashMap<u32, u64> {
+        &self.pulls
+    }
+
+    /// Returns a reference to the wrapped policy.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns the arm passing `predicate` with the fewest pulls, smallest ID on ties.
+    fn most_underserved_action(&self, predicate: impl Fn(&A) -> bool) -> Option<A> {
+        self.pulls
+            .iter()
+            .map(|(action_id, &pulls)| (self.action_map.get(action_id).unwrap(), pulls))
+            .filter(|(action, _)| predicate(action))
+            .min_by_key(|(action, pulls)| (*pulls, action.id()))
+            .map(|(action, _)| action.clone())
+    }
+
+    /// Draws whether this decision is reserved for an underserved arm.
+    fn is_forced(&self) -> bool {
+        let guaranteed_budget = (self.min_share * self.action_map.len() as f64).min(1.0);
+        self.rng.lock().unwrap().random_bool(guaranteed_budget)
+    }
+}
+
+impl<P, A, R, C> Clone for FairnessWrapper<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    fn clone(&self) -> Self {
+        FairnessWrapper {
+            inner: self.inner.clone(),
+            min_share: self.min_share,
+            pulls: self.pulls.clone(),
+            action_map: self.action_map.clone(),
+            seed: self.seed,
+            rng: Mutex::new(StdRng::seed_from_u64(self.seed)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<P, A, R, C> BanditPolicy<A, R, C> for FairnessWrapper<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    /// Picks the most underserved arm with probability `min_share * K`, otherwise delegates to the inner policy.
+    fn choose_action(&self, context: &C) -> A {
+        if self.is_forced() {
+            self.most_underserved_action(|_| true).unwrap()
+        } else {
+            self.inner.choose_action(context)
+        }
+    }
+
+    /// Like `choose_action`, but a forced pick goes to the most underserved arm allowed by
+    /// `predicate`; if none is allowed, or the pick is not forced, the inner policy decides.
+    fn choose_action_where(&self, context: &C, predicate: impl Fn(&A) -> bool) -> Option<A> {
+        let forced = if self.is_forced() {
+            self.most_underserved_action(&predicate)
+        } else {
+            None
+        };
+        match forced {
+            Some(action) => Some(action),
+            None => self.inner.choose_action_where(context, predicate),
+        }
+    }
+
+    /// Counts the pull and forwards the update to the inner policy, unless it is frozen.
+    fn update(&mut self, context: &C, action: &A, reward: &R) {
+        if self.inner.is_frozen() {
+            return;
+        }
+        if let Some(pulls) = self.pulls.get_mut(&action.id()) {
+            *pulls += 1;
+        }
+        self.inner.update(context, action, reward);
+    }
+
+    /// Resets the inner policy and the pull counts.
+    fn reset(&mut self) {
+        self.inner.reset();
+        for pulls in self.pulls.values_mut() {
+            *pulls = 0;
+        }
+    }
+
+    fn reset_rng(&mut self) {
+        self.inner.reset_rng();
+        self.rng = Mutex::new(StdRng::seed_from_u64(self.seed));
+    }
+
+    /// Reseeds the inner policy with `seed` and this wrapper's RNG with its bitwise complement,
+    /// so the two random streams stay independent.
+    fn reseed(&mut self, seed: u64) {
+        self.inner.reseed(seed);
+        self.seed = !seed;
+        self.rng = Mutex::new(StdRng::seed_from_u64(self.seed));
+    }
+
+    fn set_frozen(&mut self, frozen: bool) {
+        self.inner.set_frozen(frozen);
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.inner.is_frozen()
+    }
+
+    /// Returns a fresh copy of the inner policy with pull counts cleared.
+    fn fresh(&self) -> Self {
+        FairnessWrapper {
+            inner: self.inner.fresh(),
+            min_share: self.min_share,
+            pulls: self.pulls.keys().map(|&action_id| (action_id, 0)).collect(),
+            action_map: self.action_map.clone(),
+            seed: self.seed,
+            rng: Mutex::new(StdRng::seed_from_u64(self.seed)),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn expected_reward(&self, action: &A) -> Option<f64> {
+        self.inner.expected_reward(action)
+    }
+
+    fn action_ids(&self) -> Vec<u32> {
+        self.action_map.sorted_ids()
+    }
+
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        Ok(PolicyCheckpoint::Fairness {
+            pulls: self.pulls.clone(),
+            seed: self.seed,
+            inner: Box::new(self.inner.checkpoint()?),
+        })
+    }
+
+    fn restore(&mut self, checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        let PolicyCheckpoint::Fairness { pulls, seed, inner } = checkpoint else {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "expected a Fairness checkpoint".to_string(),
+            });
+        };
+        if !self.action_map.has_same_ids(pulls) {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "checkpoint action ids do not match the policy's actions".to_string(),
+            });
+        }
+
+        self.inner.restore(inner)?;
+        self.pulls = pulls.clone();
+        self.seed = *seed;
+        self.rng = Mutex::new(StdRng::seed_from_u64(*seed));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::epsilon_greedy::EpsilonGreedyPolicy;
+    use crate::traits::entities::{DummyContext, NumericAction};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_fairness_rejects_share_above_uniform() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+        ];
+        let inner = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.1, &actions,
+        )
+        .unwrap();
+        let err = FairnessWrapper::new(inner, &actions, 0.6, 42).unwrap_err();
+        assert!(
+            matches!(err, OctopusError::InvalidParameter { ref parameter_name, .. } if parameter_name == "min_share")
+        );
+    }
+
+    #[test]
+    fn test_fairness_keeps_every_arm_above_floor() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+            NumericAction::with_id(2, 2i32, "C"),
+        ];
+        // A purely greedy inner policy would starve all but its first pick.
+        let inner = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.0, &actions,
+        )
+        .unwrap();
+        let min_share = 0.1;
+        let mut policy = FairnessWrapper::new(inner, &actions, min_share, 7).unwrap();
+        let ctx = DummyContext;
+
+        let num_steps = 5000;
+        for _ in 0..num_steps {
+            let action = policy.choose_action(&ctx);
+            let reward = if action.id() == 2 { 1.0 } else { 0.0 };
+            policy.update(&ctx, &action, &DummyReward(reward));
+        }
+
+        for action in &actions {
+            let share = policy.pulls()[&action.id()] as f64 / num_steps as f64;
+            assert!(
+                share >= min_share,
+                "arm {} got {} of the traffic",
+                action.id(),
+                share
+            );
+        }
+        assert!(policy.pulls()[&2] > policy.pulls()[&0]);
+    }
+
+    #[test]
+    fn test_fairness_choose_action_where_serves_allowed_arms() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+            NumericAction::with_id(2, 2i32, "C"),
+        ];
+        let inner = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.0, &actions,
+        )
+        .unwrap();
+        // A uniform share forces every pick onto the most underserved arm.
+        let mut policy = FairnessWrapper::new(inner, &actions, 1.0 / 3.0, 7).unwrap();
+        let ctx = DummyContext;
+        policy.update(&ctx, &actions[0], &DummyReward(1.0));
+        policy.update(&ctx, &actions[1], &DummyReward(0.0));
+
+        assert_eq!(policy.choose_action(&ctx).id(), 2);
+        let chosen = policy.choose_action_where(&ctx, |action| action.id() != 2);
+        assert_eq!(chosen.unwrap().id(), 0);
+        assert_eq!(policy.choose_action_where(&ctx, |_| false), None);
+    }
+}
@@ -0,0 +1,220 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::traits::entities::{Action, Context, Reward};
+use crate::traits::policy::{BanditPolicy, PolicyCheckpoint};
+use crate::utils::error::OctopusError;
+
+/// A single audited decision: the summarized context, the chosen action, and the observed reward.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecisionRecord {
+    /// User-provided summary of the context the decision was made in.
+    pub context_summary: String,
+    /// ID of the action that was chosen.
+    pub action_id: u32,
+    /// Scalar value of the reward observed for the action.
+    pub reward_value: f64,
+}
+
+/// Policy wrapper that records every decision for auditing.
+///
+/// Delegates selection and learning to the inner policy, and appends a `DecisionRecord`
+/// to an in-memory log on each `update`. Contexts are summarized by a user-provided closure.
+pub struct LoggingPolicy<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    inner: P,
+    summarize_context: Arc<dyn Fn(&C) -> String + Send + Sync>,
+    log: Vec<DecisionRecord>,
+    _phantom: PhantomData<(A, R)>,
+}
+
+impl<P, A, R, C> LoggingPolicy<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    /// Creates a new LoggingPolicy.
+    ///
+    /// * `inner` - The policy that makes the actual decisions.
+    /// * `summarize_context` - Closure turning a context into a short, loggable summary.
+    pub fn new<F>(inner: P, summarize_context: F) -> Self
+    where
+        F: Fn(&C) -> String + Send + Sync + 'static,
+    {
+        LoggingPolicy {
+            inner,
+            summarize_context: Arc::new(summarize_context),
+            log: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns all decisions recorded so far, in the order they were observed.
+    pub fn decision_log(&self) -> &[DecisionRecord] {
+        &self.log
+    }
+
+    /// Returns a reference to the wrapped policy.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+}
+
+impl<P, A, R, C> Clone for LoggingPolicy<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    fn clone(&self) -> Self {
+        LoggingPolicy {
+            inner: self.inner.clone(),
+            summarize_context: Arc::clone(&self.summarize_context),
+            log: self.log.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<P, A, R, C> BanditPolicy<A, R, C> for LoggingPolicy<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    fn choose_action(&self, context: &C) -> A {
+        self.inner.choose_action(context)
+    }
+
+    fn choose_action_scored(&self, context: &C) -> (A, f64) {
+        self.inner.choose_action_scored(context)
+    }
+
+    fn choose_action_where(&self, context: &C, predicate: impl Fn(&A) -> bool) -> Option<A> {
+        self.inner.choose_action_where(context, predicate)
+    }
+
+    /// Records the decision, then forwards the update to the inner policy.
+    fn update(&mut self, context: &C, action: &A, reward: &R) {
+        self.log.push(DecisionRecord {
+            context_summary: (self.summarize_context)(context),
+            action_id: action.id(),
+            reward_value: reward.value(),
+        });
+        self.inner.update(context, action, reward);
+    }
+
+    /// Resets the inner policy and clears the decision log.
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.log.clear();
+    }
+
+    fn reset_rng(&mut self) {
+        self.inner.reset_rng();
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.inner.reseed(seed);
+    }
+
+    fn set_frozen(&mut self, frozen: bool) {
+        self.inner.set_frozen(frozen);
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.inner.is_frozen()
+    }
+
+    /// Returns a fresh copy of the inner policy with an empty decision log.
+    fn fresh(&self) -> Self {
+        LoggingPolicy {
+            inner: self.inner.fresh(),
+            summarize_context: Arc::clone(&self.summarize_context),
+            log: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn expected_reward(&self, action: &A) -> Option<f64> {
+        self.inner.expected_reward(action)
+    }
+
+    fn selection_probability(&self, context: &C, action: &A) -> Option<f64> {
+        self.inner.selection_probability(context, action)
+    }
+
+    fn selection_entropy(&self, context: &C) -> f64 {
+        self.inner.selection_entropy(context)
+    }
+
+    fn action_ids(&self) -> Vec<u32> {
+        self.inner.action_ids()
+    }
+
+    /// Checkpoints the inner policy; the decision log is not part of the learned state.
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        self.inner.checkpoint()
+    }
+
+    fn restore(&mut self, checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        self.inner.restore(checkpoint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::epsilon_greedy::EpsilonGreedyPolicy;
+    use crate::traits::entities::{DummyContext, NumericAction};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_logging_policy_records_every_cycle() {
+        let actions = vec![
+            NumericAction::with_id(0, 10i32, "A"),
+            NumericAction::with_id(1, 20i32, "B"),
+        ];
+        let inner = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.5, &actions,
+        )
+        .unwrap();
+        let mut policy = LoggingPolicy::new(inner, |_ctx: &DummyContext| "dummy".to_string());
+        let ctx = DummyContext;
+
+        let mut expected = Vec::new();
+        for step in 0..20 {
+            let action = policy.choose_action(&ctx);
+            let reward = DummyReward(step as f64);
+            policy.update(&ctx, &action, &reward);
+            expected.push(DecisionRecord {
+                context_summary: "dummy".to_string(),
+                action_id: action.id(),
+                reward_value: step as f64,
+            });
+        }
+
+        assert_eq!(policy.decision_log().len(), 20);
+        assert_eq!(policy.decision_log(), expected.as_slice());
+
+        policy.reset();
+        assert!(policy.decision_log().is_empty());
+    }
+}
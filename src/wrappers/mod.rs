@@ -0,0 +1,9 @@
+//! Policy wrappers.
+//!
+//! This module contains adapters that wrap an existing bandit policy to add cross-cutting behaviour, such as decision caching, logging and per-arm traffic guarantees.
+//! All wrappers implement the BanditPolicy trait themselves and delegate the actual learning to the inner policy.
+
+pub mod caching;
+pub mod fairness;
+pub mod logging;
+pub mod warmup;
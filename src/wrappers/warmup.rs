@@ -0,0 +1,326 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use crate::traits::entities::{Action, ActionStorage, Context, Reward};
+use crate::traits::policy::{BanditPolicy, PolicyCheckpoint};
+use crate::utils::error::OctopusError;
+
+/// Policy wrapper that pulls every arm a fixed number of times before handing over to the inner policy.
+///
+/// During warmup, the wrapper round-robins through the arms that are still under-pulled
+/// (fewest pulls first, then smallest ID). All updates are forwarded to the inner policy,
+/// so it starts exploiting with meaningful estimates once warmup is over.
+#[derive(Debug)]
+pub struct WarmupWrapper<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    inner: P,
+    pulls_per_arm: u64,
+    pulls: HashMap<u32, u64>,
+    action_map: ActionStorage<A>,
+    _phantom: PhantomData<(R, C)>,
+}
+
+impl<P, A, R, C> WarmupWrapper<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    /// Creates a new WarmupWrapper.
+    ///
+    /// * `inner` - The policy to delegate to once warmup is over.
+    /// * `initial_actions` - Slice of all possible actions.
+    /// * `pulls_per_arm` - Number of times each arm is pulled before delegating.
+    ///
+    /// Returns an error if actions are empty.
+    pub fn new(inner: P, initial_actions: &[A], pulls_per_arm: u64) -> Result<Self, OctopusError> {
+        if initial_actions.is_empty() {
+            return Err(OctopusError::EmptyCollection {
+                collection_name: "initial_actions".to_string(),
+            });
+        }
+        Ok(WarmupWrapper {
+            inner,
+            pulls_per_arm,
+            pulls: initial_actions.iter().map(|action| (action.id(), 0)).collect(),
+            action_map: ActionStorage::new(initial_actions)?,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the IDs of the arms that have not yet been pulled `pulls_per_arm` times, sorted by ID.
+    pub fn under_pulled_ids(&self) -> Vec<u32> {
+        let mut action_ids: Vec<u32> = self
+            .pulls
+            .iter()
+            .filter(|&(_, &pulls)| pulls < self.pulls_per_arm)
+            .map(|(&action_id, _)| action_id)
+            .collect();
+        action_ids.sort_unstable();
+        action_ids
+    }
+
+    /// Returns true while at least one arm is still under-pulled.
+    pub fn is_warming_up(&self) -> bool {
+        self.pulls.values().any(|&pulls| pulls < self.pulls_per_arm)
+    }
+
+    /// Returns a reference to the wrapped policy.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns the under-pulled arm passing `predicate` with the fewest pulls (smallest ID on
+    /// ties), if any.
+    fn next_warmup_action(&self, predicate: impl Fn(&A) -> bool) -> Option<A> {
+        self.pulls
+            .iter()
+            .filter(|&(_, &pulls)| pulls < self.pulls_per_arm)
+            .map(|(action_id, &pulls)| (self.action_map.get(action_id).unwrap(), pulls))
+            .filter(|(action, _)| predicate(action))
+            .min_by_key(|(action, pulls)| (*pulls, action.id()))
+            .map(|(action, _)| action.clone())
+    }
+}
+
+impl<P, A, R, C> Clone for WarmupWrapper<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    fn clone(&self) -> Self {
+        WarmupWrapper {
+            inner: self.inner.clone(),
+            pulls_per_arm: self.pulls_per_arm,
+            pulls: self.pulls.clone(),
+            action_map: self.action_map.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<P, A, R, C> BanditPolicy<A, R, C> for WarmupWrapper<P, A, R, C>
+where
+    P: BanditPolicy<A, R, C>,
+    A: Action,
+    R: Reward,
+    C: Context,
+{
+    /// Returns the next under-pulled arm during warmup, otherwise delegates to the inner policy.
+    fn choose_action(&self, context: &C) -> A {
+        self.next_warmup_action(|_| true)
+            .unwrap_or_else(|| self.inner.choose_action(context))
+    }
+
+    /// Returns the next under-pulled arm allowed by `predicate`, otherwise delegates to the inner
+    /// policy.
+    fn choose_action_where(&self, context: &C, predicate: impl Fn(&A) -> bool) -> Option<A> {
+        match self.next_warmup_action(&predicate) {
+            Some(action) => Some(action),
+            None => self.inner.choose_action_where(context, predicate),
+        }
+    }
+
+    /// Counts the pull towards warmup and forwards the update to the inner policy, unless it is frozen.
+    fn update(&mut self, context: &C, action: &A, reward: &R) {
+        if self.inner.is_frozen() {
+            return;
+        }
+        if let Some(pulls) = self.pulls.get_mut(&action.id()) {
+            *pulls += 1;
+        }
+        self.inner.update(context, action, reward);
+    }
+
+    /// Resets the inner policy and restarts warmup.
+    fn reset(&mut self) {
+        self.inner.reset();
+        for pulls in self.pulls.values_mut() {
+            *pulls = 0;
+        }
+    }
+
+    fn reset_rng(&mut self) {
+        self.inner.reset_rng();
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.inner.reseed(seed);
+    }
+
+    fn set_frozen(&mut self, frozen: bool) {
+        self.inner.set_frozen(frozen);
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.inner.is_frozen()
+    }
+
+    /// Returns a fresh copy of the inner policy with warmup restarted.
+    fn fresh(&self) -> Self {
+        WarmupWrapper {
+            inner: self.inner.fresh(),
+            pulls_per_arm: self.pulls_per_arm,
+            pulls: self.pulls.keys().map(|&action_id| (action_id, 0)).collect(),
+            action_map: self.action_map.clone(),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn expected_reward(&self, action: &A) -> Option<f64> {
+        self.inner.expected_reward(action)
+    }
+
+    fn action_ids(&self) -> Vec<u32> {
+        self.action_map.sorted_ids()
+    }
+
+    fn checkpoint(&self) -> Result<PolicyCheckpoint, OctopusError> {
+        Ok(PolicyCheckpoint::Warmup {
+            pulls: self.pulls.clone(),
+            inner: Box::new(self.inner.checkpoint()?),
+        })
+    }
+
+    fn restore(&mut self, checkpoint: &PolicyCheckpoint) -> Result<(), OctopusError> {
+        let PolicyCheckpoint::Warmup { pulls, inner } = checkpoint else {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "expected a Warmup checkpoint".to_string(),
+            });
+        };
+        if !self.action_map.has_same_ids(pulls) {
+            return Err(OctopusError::InvalidCheckpoint {
+                reason: "checkpoint action ids do not match the policy's actions".to_string(),
+            });
+        }
+
+        self.inner.restore(inner)?;
+        self.pulls = pulls.clone();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::epsilon_greedy::EpsilonGreedyPolicy;
+    use crate::traits::entities::{DummyContext, NumericAction};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct DummyReward(f64);
+
+    impl Reward for DummyReward {
+        fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_warmup_init_empty_error() {
+        let actions: Vec<NumericAction<i32>> = vec![];
+        let inner = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.0, &actions,
+        )
+        .unwrap();
+        let err = WarmupWrapper::new(inner, &actions, 3).unwrap_err();
+        assert_eq!(
+            err,
+            OctopusError::EmptyCollection {
+                collection_name: "initial_actions".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_warmup_pulls_each_arm_n_times_before_delegating() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+            NumericAction::with_id(2, 2i32, "C"),
+        ];
+        let inner = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.0, &actions,
+        )
+        .unwrap();
+        let mut policy = WarmupWrapper::new(inner, &actions, 4).unwrap();
+        let ctx = DummyContext;
+
+        let mut chosen_counts: HashMap<u32, u64> = HashMap::new();
+        for _ in 0..(4 * actions.len()) {
+            assert!(policy.is_warming_up());
+            let action = policy.choose_action(&ctx);
+            *chosen_counts.entry(action.id()).or_insert(0) += 1;
+            // Arm "B" is the best, so the inner policy will favour it afterwards.
+            let reward = if action.id() == 1 { 1.0 } else { 0.0 };
+            policy.update(&ctx, &action, &DummyReward(reward));
+        }
+
+        for action in &actions {
+            assert_eq!(*chosen_counts.get(&action.id()).unwrap(), 4);
+        }
+        assert!(!policy.is_warming_up());
+        assert!(policy.under_pulled_ids().is_empty());
+        assert_eq!(policy.choose_action(&ctx).id(), 1);
+
+        policy.reset();
+        assert_eq!(policy.under_pulled_ids(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_warmup_choose_action_where_skips_excluded_arms() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+            NumericAction::with_id(2, 2i32, "C"),
+        ];
+        let inner = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.0, &actions,
+        )
+        .unwrap();
+        let mut policy = WarmupWrapper::new(inner, &actions, 1).unwrap();
+        let ctx = DummyContext;
+        let not_a = |action: &NumericAction<i32>| action.id() != 0;
+
+        policy.update(&ctx, &actions[1], &DummyReward(1.0));
+        assert_eq!(policy.choose_action_where(&ctx, not_a).unwrap().id(), 2);
+        policy.update(&ctx, &actions[2], &DummyReward(0.0));
+
+        // Only the excluded arm is still under-pulled, so the inner policy decides.
+        assert_eq!(policy.under_pulled_ids(), vec![0]);
+        assert_eq!(policy.choose_action_where(&ctx, not_a).unwrap().id(), 1);
+        assert_eq!(policy.choose_action(&ctx).id(), 0);
+    }
+
+    #[test]
+    fn test_warmup_forwards_frozen_flag() {
+        let actions = vec![
+            NumericAction::with_id(0, 0i32, "A"),
+            NumericAction::with_id(1, 1i32, "B"),
+        ];
+        let inner = EpsilonGreedyPolicy::<NumericAction<i32>, DummyReward, DummyContext>::new(
+            0.0, &actions,
+        )
+        .unwrap();
+        let mut policy = WarmupWrapper::new(inner, &actions, 2).unwrap();
+        let ctx = DummyContext;
+
+        policy.set_frozen(true);
+        assert!(policy.is_frozen());
+        assert!(policy.inner().is_frozen());
+        policy.update(&ctx, &actions[0], &DummyReward(1.0));
+        assert_eq!(policy.under_pulled_ids(), vec![0, 1]);
+        assert_eq!(policy.inner().expected_reward(&actions[0]), Some(0.0));
+
+        policy.set_frozen(false);
+        policy.update(&ctx, &actions[0], &DummyReward(1.0));
+        assert_eq!(policy.inner().expected_reward(&actions[0]), Some(1.0));
+    }
+}